@@ -0,0 +1,108 @@
+const ALPHABET_SIZE: usize = 256;
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Run {
+    pub start: usize,
+    pub length: usize,
+}
+
+pub struct LineReport {
+    pub markers: Vec<usize>,
+    pub longest_run: Run,
+}
+
+impl LineReport {
+    pub fn from_line(line: &str, marker_size: usize) -> Self {
+        LineReport {
+            markers: find_markers(line, marker_size),
+            longest_run: get_longest_distinct_run(line),
+        }
+    }
+
+    pub fn get_first_marker(&self) -> Option<usize> {
+        self.markers.first().copied()
+    }
+}
+
+struct Window {
+    counts: [usize; ALPHABET_SIZE],
+    duplicates_count: usize,
+}
+
+impl Window {
+    fn new() -> Self {
+        Window {
+            counts: [0; ALPHABET_SIZE],
+            duplicates_count: 0,
+        }
+    }
+
+    fn push(&mut self, c: u8) {
+        let count = &mut self.counts[c as usize];
+        *count += 1;
+        if *count == 2 {
+            self.duplicates_count += 1;
+        }
+    }
+
+    fn pop(&mut self, c: u8) {
+        let count = &mut self.counts[c as usize];
+        *count -= 1;
+        if *count == 1 {
+            self.duplicates_count -= 1;
+        }
+    }
+
+    fn contains(&self, c: u8) -> bool {
+        self.counts[c as usize] > 0
+    }
+
+    fn is_distinct(&self) -> bool {
+        self.duplicates_count == 0
+    }
+}
+
+pub fn find_markers(line: &str, marker_size: usize) -> Vec<usize> {
+    if marker_size == 0 {
+        return Vec::new();
+    }
+    let bytes = line.as_bytes();
+    let mut window = Window::new();
+    let mut markers = Vec::new();
+    for (pos, c) in bytes.iter().enumerate() {
+        window.push(*c);
+        if pos >= marker_size {
+            window.pop(bytes[pos - marker_size]);
+        }
+        if pos + 1 >= marker_size && window.is_distinct() {
+            markers.push(pos + 1);
+        }
+    }
+    markers
+}
+
+pub fn find_first_marker(line: &str, marker_size: usize) -> Option<usize> {
+    find_markers(line, marker_size).first().copied()
+}
+
+pub fn get_longest_distinct_run(line: &str) -> Run {
+    let bytes = line.as_bytes();
+    let mut window = Window::new();
+    let mut start = 0;
+    let mut longest_run = Run {
+        start: 0,
+        length: 0,
+    };
+    for (pos, c) in bytes.iter().enumerate() {
+        while window.contains(*c) {
+            window.pop(bytes[start]);
+            start += 1;
+        }
+        window.push(*c);
+        let length = pos + 1 - start;
+        if length > longest_run.length {
+            longest_run = Run { start, length };
+        }
+    }
+    longest_run
+}
@@ -1,42 +1,43 @@
-use std::collections::HashSet;
 use std::env;
 use std::fs::File;
 use std::io::{self, BufRead};
 use std::path::Path;
 
-const MARKER_SIZE: usize = 14;
+use day_02_1::LineReport;
 
-fn predicate(line: &str, pos: usize) -> Option<usize> {
-    if pos <= MARKER_SIZE {
-        return None;
-    }
-    if line.len() <= MARKER_SIZE {
-        return None;
-    }
-    let slice = &line[pos - MARKER_SIZE..pos];
-    let mut char_set = HashSet::new();
-    match slice.bytes().all(|c| char_set.insert(c)) {
-        true => Some(pos),
-        false => None,
-    }
-}
+const MARKER_SIZE: usize = 14;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
     let path = &args[1];
     let path = Path::new(path);
+    let marker_size = match args.get(2) {
+        Some(marker_size) => marker_size.parse().unwrap(),
+        None => MARKER_SIZE,
+    };
     let file = File::open(path).unwrap();
-    let mut lines = io::BufReader::new(file).lines();
-    let line = lines.next().unwrap();
-    let line = line.ok().unwrap();
+    let lines = io::BufReader::new(file).lines();
 
-    let result = line
-        .chars()
+    lines
+        .map(|line| line.unwrap())
         .enumerate()
-        .find_map(|(pos, _)| predicate(&line, pos));
-
-    match result {
-        Some(pos) => println!("Marker position is: {}", pos),
-        None => println!("Could not find a marker"),
-    }
+        .for_each(|(line_index, line)| {
+            let report = LineReport::from_line(&line, marker_size);
+            match report.get_first_marker() {
+                Some(pos) => println!("Line {}: marker position is: {}", line_index + 1, pos),
+                None => println!("Line {}: could not find a marker", line_index + 1),
+            }
+            println!(
+                "Line {}: {} markers found: {:?}",
+                line_index + 1,
+                report.markers.len(),
+                report.markers
+            );
+            println!(
+                "Line {}: longest run of distinct characters starts at {} with a length of {}",
+                line_index + 1,
+                report.longest_run.start,
+                report.longest_run.length
+            );
+        });
 }
@@ -0,0 +1,137 @@
+mod find_markers {
+    use day_02_1::{find_first_marker, find_markers};
+
+    #[test]
+    fn test_packet_markers() {
+        // Given
+        let lines = [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11),
+        ];
+
+        for (line, expected) in lines {
+            // When
+            let result = find_first_marker(line, 4);
+
+            // Then
+            assert_eq!(result, Some(expected), "line `{}`", line);
+        }
+    }
+
+    #[test]
+    fn test_message_markers() {
+        // Given
+        let lines = [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
+        ];
+
+        for (line, expected) in lines {
+            // When
+            let result = find_first_marker(line, 14);
+
+            // Then
+            assert_eq!(result, Some(expected), "line `{}`", line);
+        }
+    }
+
+    #[test]
+    fn test_all_markers() {
+        // Given
+        let line = "aabcdbcd";
+
+        // When
+        let result = find_markers(line, 3);
+
+        // Then
+        assert_eq!(result, vec![4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_marker_at_start() {
+        // Given
+        let line = "abcd";
+
+        // When
+        let result = find_markers(line, 4);
+
+        // Then
+        assert_eq!(result, vec![4]);
+    }
+
+    #[test]
+    fn test_line_shorter_than_marker() {
+        // Given
+        let line = "abc";
+
+        // When
+        let result = find_markers(line, 4);
+
+        // Then
+        assert!(result.is_empty());
+    }
+}
+
+mod get_longest_distinct_run {
+    use day_02_1::{get_longest_distinct_run, Run};
+
+    #[test]
+    fn test_simple() {
+        // Given
+        let line = "abcabcbb";
+
+        // When
+        let result = get_longest_distinct_run(line);
+
+        // Then
+        assert_eq!(
+            result,
+            Run {
+                start: 0,
+                length: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_run_in_the_middle() {
+        // Given
+        let line = "aabcdeff";
+
+        // When
+        let result = get_longest_distinct_run(line);
+
+        // Then
+        assert_eq!(
+            result,
+            Run {
+                start: 1,
+                length: 6
+            }
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        // Given
+        let line = "";
+
+        // When
+        let result = get_longest_distinct_run(line);
+
+        // Then
+        assert_eq!(
+            result,
+            Run {
+                start: 0,
+                length: 0
+            }
+        );
+    }
+}
@@ -0,0 +1,335 @@
+use std::cell::Cell;
+use std::collections::{HashMap, VecDeque};
+
+pub type DirectoryId = usize;
+
+const ROOT_ID: DirectoryId = 0;
+const PATH_SEPARATOR: char = '/';
+
+#[derive(Clone, Debug)]
+pub struct FileContent {
+    pub name: String,
+    pub size: usize,
+}
+
+pub enum DirectoryEntry {
+    File(FileContent),
+    Directory(DirectoryId),
+}
+
+pub struct Directory {
+    pub name: String,
+    pub parent: Option<DirectoryId>,
+    pub children: HashMap<String, DirectoryEntry>,
+    total_size: Cell<Option<usize>>,
+}
+
+impl Directory {
+    fn new(name: String, parent: Option<DirectoryId>) -> Self {
+        Directory {
+            name,
+            parent,
+            children: HashMap::new(),
+            total_size: Cell::new(None),
+        }
+    }
+
+    pub fn sorted_children(&self) -> Vec<(&String, &DirectoryEntry)> {
+        let mut children: Vec<_> = self.children.iter().collect();
+        children.sort_by_key(|(name, _)| *name);
+        children
+    }
+
+    pub fn files(&self) -> impl Iterator<Item = &FileContent> {
+        self.children.values().filter_map(|child| match child {
+            DirectoryEntry::File(file) => Some(file),
+            DirectoryEntry::Directory(_) => None,
+        })
+    }
+
+    pub fn sub_directories(&self) -> impl Iterator<Item = DirectoryId> + '_ {
+        self.children.values().filter_map(|child| match child {
+            DirectoryEntry::File(_) => None,
+            DirectoryEntry::Directory(id) => Some(*id),
+        })
+    }
+}
+
+pub trait Visitor {
+    fn visit_directory(&mut self, file_system: &FileSystem, id: DirectoryId, depth: usize);
+
+    fn visit_file(&mut self, _file: &FileContent, _depth: usize) {}
+}
+
+pub struct FileSystem {
+    directories: Vec<Directory>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileSystem {
+    pub fn new() -> Self {
+        let root = Directory::new(PATH_SEPARATOR.to_string(), None);
+        FileSystem {
+            directories: vec![root],
+        }
+    }
+
+    pub fn get_root_id(&self) -> DirectoryId {
+        ROOT_ID
+    }
+
+    pub fn get_directory(&self, id: DirectoryId) -> &Directory {
+        &self.directories[id]
+    }
+
+    pub fn get_size(&self, id: DirectoryId) -> usize {
+        let directory = self.get_directory(id);
+        match directory.total_size.get() {
+            Some(total_size) => total_size,
+            None => {
+                let total_size = self.compute_size(directory);
+                directory.total_size.replace(Some(total_size));
+                total_size
+            }
+        }
+    }
+
+    fn compute_size(&self, directory: &Directory) -> usize {
+        directory
+            .children
+            .values()
+            .map(|child| match child {
+                DirectoryEntry::File(file) => file.size,
+                DirectoryEntry::Directory(id) => self.get_size(*id),
+            })
+            .sum()
+    }
+
+    fn invalidate_size(&self, id: DirectoryId) {
+        let mut current = Some(id);
+        while let Some(id) = current {
+            let directory = self.get_directory(id);
+            directory.total_size.replace(None);
+            current = directory.parent;
+        }
+    }
+
+    pub fn add_file(&mut self, parent: DirectoryId, file: FileContent) {
+        self.check_duplicate(parent, &file.name);
+        let name = file.name.clone();
+        self.directories[parent]
+            .children
+            .insert(name, DirectoryEntry::File(file));
+        self.invalidate_size(parent);
+    }
+
+    pub fn add_directory(&mut self, parent: DirectoryId, name: String) -> DirectoryId {
+        self.check_duplicate(parent, &name);
+        let id = self.directories.len();
+        self.directories
+            .push(Directory::new(name.clone(), Some(parent)));
+        self.directories[parent]
+            .children
+            .insert(name, DirectoryEntry::Directory(id));
+        id
+    }
+
+    pub fn merge_file(&mut self, parent: DirectoryId, file: FileContent) {
+        match self.get_child(parent, &file.name) {
+            None => self.add_file(parent, file),
+            Some(DirectoryEntry::Directory(_)) => panic!(
+                "Cannot list file `{}` in `{}` as it was already listed as a directory",
+                file.name,
+                self.get_path(parent)
+            ),
+            Some(DirectoryEntry::File(previous_file)) => {
+                if previous_file.size != file.size {
+                    let name = file.name.clone();
+                    self.directories[parent]
+                        .children
+                        .insert(name, DirectoryEntry::File(file));
+                    self.invalidate_size(parent);
+                }
+            }
+        }
+    }
+
+    pub fn merge_directory(&mut self, parent: DirectoryId, name: String) -> DirectoryId {
+        match self.get_child(parent, &name) {
+            None => self.add_directory(parent, name),
+            Some(DirectoryEntry::Directory(id)) => *id,
+            Some(DirectoryEntry::File(_)) => panic!(
+                "Cannot list directory `{}` in `{}` as it was already listed as a file",
+                name,
+                self.get_path(parent)
+            ),
+        }
+    }
+
+    pub fn remove_entry(&mut self, parent: DirectoryId, name: &str) -> Option<DirectoryEntry> {
+        let entry = self.directories[parent].children.remove(name)?;
+        self.invalidate_size(parent);
+        Some(entry)
+    }
+
+    fn check_duplicate(&self, parent: DirectoryId, name: &str) {
+        let directory = self.get_directory(parent);
+        if directory.children.contains_key(name) {
+            panic!(
+                "The current directory `{}` has already an entry with name `{}`",
+                directory.name, name
+            );
+        }
+    }
+
+    pub fn get_child(&self, parent: DirectoryId, name: &str) -> Option<&DirectoryEntry> {
+        self.get_directory(parent).children.get(name)
+    }
+
+    pub fn get_parent(&self, id: DirectoryId) -> Option<DirectoryId> {
+        self.get_directory(id).parent
+    }
+
+    // Both lookups only accept absolute paths, use `resolve_entry` and
+    // `resolve_directory` for paths relative to a directory
+    pub fn find_entry(&self, path: &str) -> Option<&DirectoryEntry> {
+        if !path.starts_with(PATH_SEPARATOR) {
+            return None;
+        }
+        self.resolve_entry(ROOT_ID, path)
+    }
+
+    pub fn find_directory(&self, path: &str) -> Option<DirectoryId> {
+        if !path.starts_with(PATH_SEPARATOR) {
+            return None;
+        }
+        self.resolve_directory(ROOT_ID, path)
+    }
+
+    pub fn resolve_entry(&self, from: DirectoryId, path: &str) -> Option<&DirectoryEntry> {
+        let path = path.trim_end_matches(PATH_SEPARATOR);
+        match path.rsplit_once(PATH_SEPARATOR) {
+            None => self.get_child(from, path),
+            Some((parent_path, name)) => {
+                let parent = match parent_path.is_empty() {
+                    true => ROOT_ID,
+                    false => self.resolve_directory(from, parent_path)?,
+                };
+                self.get_child(parent, name)
+            }
+        }
+    }
+
+    pub fn resolve_directory(&self, from: DirectoryId, path: &str) -> Option<DirectoryId> {
+        let from = match path.starts_with(PATH_SEPARATOR) {
+            true => ROOT_ID,
+            false => from,
+        };
+        path.split(PATH_SEPARATOR)
+            .filter(|segment| !segment.is_empty())
+            .try_fold(from, |id, segment| match segment {
+                "." => Some(id),
+                ".." => self.get_parent(id),
+                _ => match self.get_child(id, segment) {
+                    Some(DirectoryEntry::Directory(child_id)) => Some(*child_id),
+                    _ => None,
+                },
+            })
+    }
+
+    pub fn get_path(&self, id: DirectoryId) -> String {
+        let mut names = Vec::new();
+        let mut current = id;
+        while let Some(parent) = self.get_parent(current) {
+            names.push(self.get_directory(current).name.as_str());
+            current = parent;
+        }
+        names.reverse();
+        format!("{}{}", PATH_SEPARATOR, names.join("/"))
+    }
+
+    pub fn directories(&self) -> DirectoryIterator<'_> {
+        DirectoryIterator::from_root(self, ROOT_ID)
+    }
+
+    pub fn walk_depth_first<V: Visitor>(&self, visitor: &mut V) {
+        self.walk_depth_first_rec(ROOT_ID, 0, visitor);
+    }
+
+    fn walk_depth_first_rec<V: Visitor>(&self, id: DirectoryId, depth: usize, visitor: &mut V) {
+        visitor.visit_directory(self, id, depth);
+        self.get_directory(id)
+            .sorted_children()
+            .into_iter()
+            .for_each(|(_, child)| match child {
+                DirectoryEntry::File(file) => visitor.visit_file(file, depth + 1),
+                DirectoryEntry::Directory(child_id) => {
+                    self.walk_depth_first_rec(*child_id, depth + 1, visitor)
+                }
+            });
+    }
+
+    pub fn walk_breadth_first<V: Visitor>(&self, visitor: &mut V) {
+        let root = DirectoryEntry::Directory(ROOT_ID);
+        let mut queue = VecDeque::from([(&root, 0)]);
+        while let Some((entry, depth)) = queue.pop_front() {
+            match entry {
+                DirectoryEntry::File(file) => visitor.visit_file(file, depth),
+                DirectoryEntry::Directory(id) => {
+                    visitor.visit_directory(self, *id, depth);
+                    self.get_directory(*id)
+                        .sorted_children()
+                        .into_iter()
+                        .for_each(|(_, child)| queue.push_back((child, depth + 1)));
+                }
+            }
+        }
+    }
+
+    pub fn print(&self) {
+        self.walk_depth_first(&mut PrintVisitor {});
+    }
+}
+
+struct PrintVisitor {}
+
+impl Visitor for PrintVisitor {
+    fn visit_directory(&mut self, file_system: &FileSystem, id: DirectoryId, depth: usize) {
+        let precursor = "  ".repeat(depth);
+        let directory = file_system.get_directory(id);
+        println!("{}- {} (dir)", precursor, directory.name);
+    }
+
+    fn visit_file(&mut self, file: &FileContent, depth: usize) {
+        let precursor = "  ".repeat(depth);
+        println!("{}- {} (file, size={})", precursor, file.name, file.size);
+    }
+}
+
+pub struct DirectoryIterator<'a> {
+    file_system: &'a FileSystem,
+    file: Vec<DirectoryId>,
+}
+
+impl<'a> DirectoryIterator<'a> {
+    fn from_root(file_system: &'a FileSystem, root: DirectoryId) -> Self {
+        let file = vec![root];
+        DirectoryIterator { file_system, file }
+    }
+}
+
+impl<'a> Iterator for DirectoryIterator<'a> {
+    type Item = DirectoryId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = self.file.pop()?;
+        self.file
+            .extend(self.file_system.get_directory(id).sub_directories());
+        Some(id)
+    }
+}
@@ -0,0 +1,14 @@
+mod file_system;
+mod planner;
+mod report;
+mod terminal;
+
+pub use file_system::{
+    Directory, DirectoryEntry, DirectoryId, DirectoryIterator, FileContent, FileSystem, Visitor,
+};
+pub use planner::{Deletion, DeletionPlan, Device, DISK_SIZE, UPDATE_REQUIRED_SPACE};
+pub use report::{
+    format_size, get_directories_under, get_largest_directories, render_du, render_tree, to_json,
+    Report,
+};
+pub use terminal::{Command, DirectoryMove, Entry, Line, Session};
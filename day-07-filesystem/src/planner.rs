@@ -0,0 +1,178 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::file_system::{DirectoryEntry, DirectoryId, FileSystem};
+
+pub const DISK_SIZE: usize = 70000000;
+pub const UPDATE_REQUIRED_SPACE: usize = 30000000;
+
+const BITS_PER_WORD: usize = u64::BITS as usize;
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum Deletion {
+    Directory(DirectoryId),
+    File(DirectoryId, String),
+}
+
+pub struct DeletionPlan {
+    pub deletions: Vec<Deletion>,
+    pub freed_space: usize,
+}
+
+pub struct Device {
+    pub disk_size: usize,
+    pub required_space: usize,
+}
+
+impl Default for Device {
+    fn default() -> Self {
+        Device {
+            disk_size: DISK_SIZE,
+            required_space: UPDATE_REQUIRED_SPACE,
+        }
+    }
+}
+
+impl Device {
+    pub fn new(disk_size: usize, required_space: usize) -> Self {
+        Device {
+            disk_size,
+            required_space,
+        }
+    }
+
+    pub fn get_missing_space(&self, file_system: &FileSystem) -> usize {
+        let file_system_size = file_system.get_size(file_system.get_root_id());
+        let available_space = self.disk_size.saturating_sub(file_system_size);
+        self.required_space.saturating_sub(available_space)
+    }
+
+    pub fn get_smallest_fitting_directory(&self, file_system: &FileSystem) -> Option<DirectoryId> {
+        let missing_space = self.get_missing_space(file_system);
+        file_system
+            .directories()
+            .filter(|id| file_system.get_size(*id) >= missing_space)
+            .min_by_key(|id| file_system.get_size(*id))
+    }
+
+    pub fn plan_deletion(&self, file_system: &FileSystem) -> Option<DeletionPlan> {
+        let missing_space = self.get_missing_space(file_system);
+        if missing_space == 0 {
+            return Some(DeletionPlan {
+                deletions: Vec::new(),
+                freed_space: 0,
+            });
+        }
+
+        // Deleting a directory frees exactly the size of the files below it, so the best set
+        // of non-nested entries is the best set of files, regrouped into whole directories.
+        let mut files: Vec<(DirectoryId, &String, usize)> =
+            file_system
+                .directories()
+                .flat_map(|id| {
+                    file_system.get_directory(id).children.iter().filter_map(
+                        move |(name, child)| match child {
+                            DirectoryEntry::File(file) => Some((id, name, file.size)),
+                            DirectoryEntry::Directory(_) => None,
+                        },
+                    )
+                })
+                .collect();
+        files.sort();
+        let max_file_size = files.iter().map(|(_, _, size)| *size).max()?;
+
+        // `reached_by[sum]` is the index of the file that first made `sum` reachable.
+        let capacity = missing_space + max_file_size;
+        let mut reachable = vec![0u64; capacity.div_ceil(BITS_PER_WORD)];
+        reachable[0] = 1;
+        let mut reached_by = HashMap::new();
+        files.iter().enumerate().for_each(|(index, (_, _, size))| {
+            let shifted = shift_left(&reachable, *size);
+            reachable.iter_mut().zip(shifted).enumerate().for_each(
+                |(word_index, (word, shifted_word))| {
+                    let mut new_bits = shifted_word & !*word;
+                    while new_bits != 0 {
+                        let bit = new_bits.trailing_zeros() as usize;
+                        reached_by.insert(word_index * BITS_PER_WORD + bit, index);
+                        new_bits &= new_bits - 1;
+                    }
+                    *word |= shifted_word;
+                },
+            );
+        });
+
+        let freed_space = (missing_space..capacity).find(|sum| reached_by.contains_key(sum))?;
+        let mut chosen_files = HashSet::new();
+        let mut sum = freed_space;
+        while sum > 0 {
+            let (directory, name, size) = files[reached_by[&sum]];
+            chosen_files.insert((directory, name.as_str()));
+            sum -= size;
+        }
+
+        let mut deletions = Vec::new();
+        let root_id = file_system.get_root_id();
+        if collect_deletions(file_system, root_id, &chosen_files, &mut deletions) {
+            deletions = vec![Deletion::Directory(root_id)];
+        }
+        Some(DeletionPlan {
+            deletions,
+            freed_space,
+        })
+    }
+}
+
+fn shift_left(words: &[u64], shift: usize) -> Vec<u64> {
+    let word_shift = shift / BITS_PER_WORD;
+    let bit_shift = shift % BITS_PER_WORD;
+    (0..words.len())
+        .map(|index| {
+            if index < word_shift {
+                return 0;
+            }
+            let source = index - word_shift;
+            let low = words[source] << bit_shift;
+            match bit_shift > 0 && source > 0 {
+                true => low | (words[source - 1] >> (BITS_PER_WORD - bit_shift)),
+                false => low,
+            }
+        })
+        .collect()
+}
+
+// Returns whether the whole directory is deleted, in which case its entries are not reported.
+fn collect_deletions(
+    file_system: &FileSystem,
+    id: DirectoryId,
+    chosen_files: &HashSet<(DirectoryId, &str)>,
+    deletions: &mut Vec<Deletion>,
+) -> bool {
+    let mut directory_deletions = Vec::new();
+    let mut is_fully_deleted = true;
+    file_system
+        .get_directory(id)
+        .sorted_children()
+        .into_iter()
+        .for_each(|(name, child)| match child {
+            DirectoryEntry::File(file) if file.size == 0 => (),
+            DirectoryEntry::Directory(child_id) if file_system.get_size(*child_id) == 0 => (),
+            DirectoryEntry::File(_) => match chosen_files.contains(&(id, name.as_str())) {
+                true => directory_deletions.push(Deletion::File(id, name.clone())),
+                false => is_fully_deleted = false,
+            },
+            DirectoryEntry::Directory(child_id) => {
+                let mut child_deletions = Vec::new();
+                match collect_deletions(file_system, *child_id, chosen_files, &mut child_deletions)
+                {
+                    true => directory_deletions.push(Deletion::Directory(*child_id)),
+                    false => {
+                        is_fully_deleted = false;
+                        directory_deletions.append(&mut child_deletions);
+                    }
+                }
+            }
+        });
+    if !is_fully_deleted {
+        deletions.append(&mut directory_deletions);
+    }
+    is_fully_deleted
+}
@@ -0,0 +1,187 @@
+use crate::file_system::{DirectoryEntry, DirectoryId, FileSystem};
+
+const SIZE_UNITS: [char; 5] = ['K', 'M', 'G', 'T', 'P'];
+const SIZE_UNIT_FACTOR: usize = 1024;
+
+pub enum Report {
+    Tree,
+    Du,
+    Json,
+}
+
+impl Report {
+    pub fn parse(word: &str) -> Self {
+        match word {
+            "tree" => Report::Tree,
+            "du" => Report::Du,
+            "json" => Report::Json,
+            _ => panic!("Invalid report format: `{}`", word),
+        }
+    }
+
+    pub fn render(&self, file_system: &FileSystem) -> String {
+        match self {
+            Report::Tree => render_tree(file_system),
+            Report::Du => render_du(file_system),
+            Report::Json => to_json(file_system),
+        }
+    }
+}
+
+pub fn render_tree(file_system: &FileSystem) -> String {
+    let root_id = file_system.get_root_id();
+    let mut lines = vec![format!(
+        "{} ({})",
+        file_system.get_directory(root_id).name,
+        format_size(file_system.get_size(root_id))
+    )];
+    render_tree_rec(file_system, root_id, "", &mut lines);
+    lines.join("\n")
+}
+
+fn render_tree_rec(
+    file_system: &FileSystem,
+    id: DirectoryId,
+    prefix: &str,
+    lines: &mut Vec<String>,
+) {
+    let children = file_system.get_directory(id).sorted_children();
+    let children_count = children.len();
+    children
+        .into_iter()
+        .enumerate()
+        .for_each(|(index, (name, child))| {
+            let is_last = index + 1 == children_count;
+            let (branch, indent) = match is_last {
+                true => ("└── ", "    "),
+                false => ("├── ", "│   "),
+            };
+            match child {
+                DirectoryEntry::File(file) => lines.push(format!(
+                    "{}{}{} ({})",
+                    prefix,
+                    branch,
+                    name,
+                    format_size(file.size)
+                )),
+                DirectoryEntry::Directory(child_id) => {
+                    lines.push(format!(
+                        "{}{}{}/ ({})",
+                        prefix,
+                        branch,
+                        name,
+                        format_size(file_system.get_size(*child_id))
+                    ));
+                    let prefix = format!("{}{}", prefix, indent);
+                    render_tree_rec(file_system, *child_id, &prefix, lines);
+                }
+            }
+        });
+}
+
+pub fn render_du(file_system: &FileSystem) -> String {
+    let mut lines = Vec::new();
+    render_du_rec(file_system, file_system.get_root_id(), &mut lines);
+    lines.join("\n")
+}
+
+fn render_du_rec(file_system: &FileSystem, id: DirectoryId, lines: &mut Vec<String>) {
+    file_system
+        .get_directory(id)
+        .sorted_children()
+        .into_iter()
+        .for_each(|(_, child)| {
+            if let DirectoryEntry::Directory(child_id) = child {
+                render_du_rec(file_system, *child_id, lines);
+            }
+        });
+    lines.push(format!(
+        "{}\t{}",
+        format_size(file_system.get_size(id)),
+        file_system.get_path(id)
+    ));
+}
+
+pub fn format_size(size: usize) -> String {
+    if size < SIZE_UNIT_FACTOR {
+        return size.to_string();
+    }
+    let mut value = size as f64;
+    let mut unit = SIZE_UNITS[0];
+    for current_unit in SIZE_UNITS {
+        value /= SIZE_UNIT_FACTOR as f64;
+        unit = current_unit;
+        if value < SIZE_UNIT_FACTOR as f64 {
+            break;
+        }
+    }
+    match value < 10.0 {
+        true => format!("{:.1}{}", (value * 10.0).ceil() / 10.0, unit),
+        false => format!("{}{}", value.ceil(), unit),
+    }
+}
+
+pub fn to_json(file_system: &FileSystem) -> String {
+    directory_to_json(file_system, file_system.get_root_id())
+}
+
+fn directory_to_json(file_system: &FileSystem, id: DirectoryId) -> String {
+    let directory = file_system.get_directory(id);
+    let children: Vec<String> = directory
+        .sorted_children()
+        .into_iter()
+        .map(|(name, child)| match child {
+            DirectoryEntry::File(file) => format!(
+                "{{\"name\":{},\"type\":\"file\",\"size\":{}}}",
+                escape_json(name),
+                file.size
+            ),
+            DirectoryEntry::Directory(child_id) => directory_to_json(file_system, *child_id),
+        })
+        .collect();
+    format!(
+        "{{\"name\":{},\"type\":\"directory\",\"size\":{},\"children\":[{}]}}",
+        escape_json(&directory.name),
+        file_system.get_size(id),
+        children.join(",")
+    )
+}
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::from('"');
+    value.chars().for_each(|c| match c {
+        '"' => escaped.push_str("\\\""),
+        '\\' => escaped.push_str("\\\\"),
+        '\n' => escaped.push_str("\\n"),
+        '\t' => escaped.push_str("\\t"),
+        c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+        c => escaped.push(c),
+    });
+    escaped.push('"');
+    escaped
+}
+
+pub fn get_largest_directories(file_system: &FileSystem, count: usize) -> Vec<DirectoryId> {
+    let mut directories: Vec<DirectoryId> = file_system.directories().collect();
+    directories.sort_by(|id_a, id_b| {
+        file_system
+            .get_size(*id_b)
+            .cmp(&file_system.get_size(*id_a))
+            .then_with(|| {
+                file_system
+                    .get_path(*id_a)
+                    .cmp(&file_system.get_path(*id_b))
+            })
+    });
+    directories.truncate(count);
+    directories
+}
+
+pub fn get_directories_under(file_system: &FileSystem, max_size: usize) -> Vec<DirectoryId> {
+    let mut directories: Vec<DirectoryId> = file_system
+        .directories()
+        .filter(|id| file_system.get_size(*id) <= max_size)
+        .collect();
+    directories.sort_by_key(|id| file_system.get_path(*id));
+    directories
+}
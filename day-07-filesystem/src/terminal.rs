@@ -0,0 +1,274 @@
+use crate::file_system::{DirectoryEntry, DirectoryId, FileContent, FileSystem};
+
+const PATH_SEPARATOR: char = '/';
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum DirectoryMove {
+    Out,
+    In(String),
+    Root,
+}
+
+impl DirectoryMove {
+    pub fn parse(word: &str) -> Vec<Self> {
+        if word.is_empty() {
+            panic!("Invalid destination for `cd`: `{}`", word);
+        }
+        let root = match word.starts_with(PATH_SEPARATOR) {
+            true => Some(DirectoryMove::Root),
+            false => None,
+        };
+        root.into_iter()
+            .chain(
+                word.split(PATH_SEPARATOR)
+                    .filter(|segment| !segment.is_empty() && *segment != ".")
+                    .map(|segment| match segment {
+                        ".." => DirectoryMove::Out,
+                        _ => DirectoryMove::In(segment.to_string()),
+                    }),
+            )
+            .collect()
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum Command {
+    Cd(Vec<DirectoryMove>),
+    Ls,
+    Pwd,
+    Rm(Vec<String>),
+    Mkdir(Vec<String>),
+    Other(String),
+}
+
+impl Command {
+    pub fn parse(line_str: &str) -> Self {
+        let mut words = line_str.split(' ').filter(|word| !word.is_empty());
+        words.next().unwrap();
+        let word = words.next().expect("Missing command after the prompt");
+        match word {
+            "ls" => Command::Ls,
+            "pwd" => Command::Pwd,
+            "cd" => {
+                let word = words.next().expect("Missing destination of `cd` command");
+                Command::Cd(DirectoryMove::parse(word))
+            }
+            "rm" => Command::Rm(Command::get_operands(words)),
+            "mkdir" => Command::Mkdir(Command::get_operands(words)),
+            _ => Command::Other(word.to_string()),
+        }
+    }
+
+    fn get_operands<'a, I: Iterator<Item = &'a str>>(words: I) -> Vec<String> {
+        words
+            .filter(|word| !word.starts_with('-'))
+            .map(|word| word.to_string())
+            .collect()
+    }
+}
+
+pub enum Entry {
+    Directory(String),
+    File(FileContent),
+}
+
+impl Entry {
+    pub fn parse(line_str: &str) -> Self {
+        let mut words = line_str.split(' ');
+        let first_word = words.next().unwrap();
+        let second_word = words.next().unwrap();
+        match first_word {
+            "dir" => Entry::Directory(second_word.to_string()),
+            _ => {
+                let name = second_word.to_string();
+                let size = first_word.parse().unwrap();
+                Entry::File(FileContent { name, size })
+            }
+        }
+    }
+}
+
+pub enum Line {
+    Command(Command),
+    Output(String),
+}
+
+impl Line {
+    pub fn parse(line_str: &str) -> Self {
+        match line_str.starts_with('$') {
+            true => Line::Command(Command::parse(line_str)),
+            false => Line::Output(line_str.to_string()),
+        }
+    }
+}
+
+enum OutputMode {
+    Listing,
+    WorkingDirectory,
+    Ignored,
+}
+
+pub struct Session {
+    pub file_system: FileSystem,
+    cd: DirectoryId,
+    output_mode: OutputMode,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Session {
+    pub fn new() -> Self {
+        let file_system = FileSystem::new();
+        let cd = file_system.get_root_id();
+        Session {
+            file_system,
+            cd,
+            output_mode: OutputMode::Ignored,
+        }
+    }
+
+    pub fn get_cd(&self) -> DirectoryId {
+        self.cd
+    }
+
+    pub fn process_line(&mut self, line: Line) {
+        match line {
+            Line::Output(output) => self.process_output(&output),
+            Line::Command(command) => self.process_command(&command),
+        }
+    }
+
+    fn process_output(&mut self, output: &str) {
+        match self.output_mode {
+            OutputMode::Listing => self.add_entry(Entry::parse(output)),
+            OutputMode::WorkingDirectory => self.check_working_directory(output),
+            OutputMode::Ignored => (),
+        }
+    }
+
+    fn add_entry(&mut self, entry: Entry) {
+        match entry {
+            Entry::File(file) => self.file_system.merge_file(self.cd, file),
+            Entry::Directory(name) => {
+                self.file_system.merge_directory(self.cd, name);
+            }
+        }
+    }
+
+    fn check_working_directory(&self, output: &str) {
+        let path = self.file_system.get_path(self.cd);
+        if path != output {
+            panic!(
+                "The session is in `{}` but `pwd` printed `{}`",
+                path, output
+            );
+        }
+    }
+
+    fn process_command(&mut self, command: &Command) {
+        self.output_mode = match command {
+            Command::Ls => OutputMode::Listing,
+            Command::Pwd => OutputMode::WorkingDirectory,
+            _ => OutputMode::Ignored,
+        };
+        match command {
+            Command::Ls | Command::Pwd | Command::Other(_) => (),
+            Command::Cd(directory_moves) => directory_moves
+                .iter()
+                .for_each(|directory_move| self.process_cd(directory_move)),
+            Command::Rm(paths) => paths.iter().for_each(|path| self.remove(path)),
+            Command::Mkdir(paths) => paths.iter().for_each(|path| self.make_directory(path)),
+        }
+    }
+
+    fn process_cd(&mut self, directory_target: &DirectoryMove) {
+        match directory_target {
+            DirectoryMove::In(name) => self.cd_in(name),
+            DirectoryMove::Out => self.cd_out(),
+            DirectoryMove::Root => self.cd_root(),
+        }
+    }
+
+    // Only `ls` and `mkdir` create directories, `cd` into an unknown one is
+    // a sign of a corrupted capture
+    fn cd_in(&mut self, name: &str) {
+        self.cd = match self.file_system.get_child(self.cd, name) {
+            Some(DirectoryEntry::Directory(id)) => *id,
+            Some(DirectoryEntry::File(_)) => {
+                panic!("cannot change directory to {} as it is a file", name)
+            }
+            None => panic!(
+                "The directory {} do not have a child {}",
+                self.file_system.get_directory(self.cd).name,
+                name
+            ),
+        };
+    }
+
+    fn cd_root(&mut self) {
+        self.cd = self.file_system.get_root_id();
+    }
+
+    fn cd_out(&mut self) {
+        self.cd = self
+            .file_system
+            .get_parent(self.cd)
+            .expect("Cannot go up when already at the root");
+    }
+
+    fn remove(&mut self, path: &str) {
+        let path = path.trim_end_matches(PATH_SEPARATOR);
+        let (parent, name) = match path.rsplit_once(PATH_SEPARATOR) {
+            None => (Some(self.cd), path),
+            Some(("", name)) => (Some(self.file_system.get_root_id()), name),
+            Some((parent_path, name)) => (
+                self.file_system.resolve_directory(self.cd, parent_path),
+                name,
+            ),
+        };
+        if let Some(parent) = parent {
+            if let Some(DirectoryEntry::Directory(id)) = self.file_system.get_child(parent, name) {
+                // The session would keep listing into a detached directory
+                if self.is_in_directory(*id) {
+                    panic!(
+                        "Cannot remove `{}` as it contains the current directory",
+                        self.file_system.get_path(*id)
+                    );
+                }
+            }
+            self.file_system.remove_entry(parent, name);
+        }
+    }
+
+    fn is_in_directory(&self, id: DirectoryId) -> bool {
+        let mut current = Some(self.cd);
+        while let Some(current_id) = current {
+            if current_id == id {
+                return true;
+            }
+            current = self.file_system.get_parent(current_id);
+        }
+        false
+    }
+
+    fn make_directory(&mut self, path: &str) {
+        let start = match path.starts_with(PATH_SEPARATOR) {
+            true => self.file_system.get_root_id(),
+            false => self.cd,
+        };
+        path.split(PATH_SEPARATOR)
+            .filter(|segment| !segment.is_empty())
+            .fold(start, |id, segment| match segment {
+                "." => id,
+                ".." => self
+                    .file_system
+                    .get_parent(id)
+                    .expect("Cannot go up when already at the root"),
+                _ => self.file_system.merge_directory(id, segment.to_string()),
+            });
+    }
+}
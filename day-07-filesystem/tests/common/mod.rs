@@ -0,0 +1,30 @@
+// Shared by several test crates, each of them only uses part of it
+#![allow(dead_code)]
+
+use day_07_filesystem::{FileContent, FileSystem};
+
+pub fn file(name: &str, size: usize) -> FileContent {
+    FileContent {
+        name: name.to_string(),
+        size,
+    }
+}
+
+pub fn build_file_system() -> FileSystem {
+    let mut file_system = FileSystem::new();
+    let root = file_system.get_root_id();
+    let a = file_system.add_directory(root, "a".to_string());
+    file_system.add_file(root, file("b.txt", 14848514));
+    file_system.add_file(root, file("c.dat", 8504156));
+    let d = file_system.add_directory(root, "d".to_string());
+    let e = file_system.add_directory(a, "e".to_string());
+    file_system.add_file(a, file("f", 29116));
+    file_system.add_file(a, file("g", 2557));
+    file_system.add_file(a, file("h.lst", 62596));
+    file_system.add_file(e, file("i", 584));
+    file_system.add_file(d, file("j", 4060174));
+    file_system.add_file(d, file("d.log", 8033020));
+    file_system.add_file(d, file("d.ext", 5626152));
+    file_system.add_file(d, file("k", 7214296));
+    file_system
+}
@@ -0,0 +1,168 @@
+mod common;
+
+mod get_size {
+    use crate::common::{build_file_system, file};
+
+    #[test]
+    fn test_sizes() {
+        // Given
+        let file_system = build_file_system();
+
+        // When
+        let root_size = file_system.get_size(file_system.get_root_id());
+        let a_size = file_system.get_size(file_system.find_directory("/a").unwrap());
+        let e_size = file_system.get_size(file_system.find_directory("/a/e").unwrap());
+        let d_size = file_system.get_size(file_system.find_directory("/d").unwrap());
+
+        // Then
+        assert_eq!(root_size, 48381165);
+        assert_eq!(a_size, 94853);
+        assert_eq!(e_size, 584);
+        assert_eq!(d_size, 24933642);
+    }
+
+    #[test]
+    fn test_invalidated_after_add() {
+        // Given
+        let mut file_system = build_file_system();
+        let e = file_system.find_directory("/a/e").unwrap();
+        let a = file_system.find_directory("/a").unwrap();
+        file_system.get_size(file_system.get_root_id());
+
+        // When
+        file_system.add_file(e, file("j", 1000));
+
+        // Then
+        assert_eq!(file_system.get_size(e), 1584);
+        assert_eq!(file_system.get_size(a), 95853);
+        assert_eq!(file_system.get_size(file_system.get_root_id()), 48382165);
+    }
+}
+
+mod find {
+    use crate::common::build_file_system;
+    use day_07_filesystem::DirectoryEntry;
+
+    #[test]
+    fn test_find_directory() {
+        // Given
+        let file_system = build_file_system();
+
+        // When
+        let e = file_system.find_directory("/a/e").unwrap();
+
+        // Then
+        assert_eq!(file_system.get_directory(e).name, "e");
+        assert_eq!(file_system.get_path(e), "/a/e");
+    }
+
+    #[test]
+    fn test_find_root() {
+        // Given
+        let file_system = build_file_system();
+
+        // When
+        let root = file_system.find_directory("/");
+
+        // Then
+        assert_eq!(root, Some(file_system.get_root_id()));
+        assert_eq!(file_system.get_path(file_system.get_root_id()), "/");
+    }
+
+    #[test]
+    fn test_find_missing() {
+        // Given
+        let file_system = build_file_system();
+
+        // When
+        let result = file_system.find_directory("/a/z");
+        let file_as_directory = file_system.find_directory("/b.txt");
+
+        // Then
+        assert_eq!(result, None);
+        assert_eq!(file_as_directory, None);
+    }
+
+    #[test]
+    fn test_find_file() {
+        // Given
+        let file_system = build_file_system();
+
+        // When
+        let result = file_system.find_entry("/a/e/i");
+
+        // Then
+        match result {
+            Some(DirectoryEntry::File(file)) => assert_eq!(file.size, 584),
+            _ => panic!("Expected `/a/e/i` to be a file"),
+        }
+    }
+
+    #[test]
+    fn test_relative_paths() {
+        // Given
+        let file_system = build_file_system();
+
+        // When
+        let entry = file_system.find_entry("a/e/i");
+        let directory = file_system.find_directory("a/e");
+
+        // Then
+        assert!(entry.is_none());
+        assert_eq!(directory, None);
+    }
+}
+
+mod visitors {
+    use crate::common::build_file_system;
+    use day_07_filesystem::{DirectoryId, FileContent, FileSystem, Visitor};
+
+    struct NamesVisitor {
+        names: Vec<(String, usize)>,
+    }
+
+    impl Visitor for NamesVisitor {
+        fn visit_directory(&mut self, file_system: &FileSystem, id: DirectoryId, depth: usize) {
+            let name = file_system.get_directory(id).name.clone();
+            self.names.push((name, depth));
+        }
+
+        fn visit_file(&mut self, file: &FileContent, depth: usize) {
+            self.names.push((file.name.clone(), depth));
+        }
+    }
+
+    #[test]
+    fn test_depth_first() {
+        // Given
+        let file_system = build_file_system();
+        let mut visitor = NamesVisitor { names: Vec::new() };
+
+        // When
+        file_system.walk_depth_first(&mut visitor);
+
+        // Then
+        assert_eq!(visitor.names.len(), 14);
+        let e_index = visitor.names.iter().position(|(name, _)| name == "e");
+        let i_index = visitor.names.iter().position(|(name, _)| name == "i");
+        assert_eq!(i_index, e_index.map(|index| index + 1));
+        assert!(visitor.names.contains(&("i".to_string(), 3)));
+    }
+
+    #[test]
+    fn test_breadth_first() {
+        // Given
+        let file_system = build_file_system();
+        let mut visitor = NamesVisitor { names: Vec::new() };
+
+        // When
+        file_system.walk_breadth_first(&mut visitor);
+
+        // Then
+        assert_eq!(visitor.names.len(), 14);
+        let depths: Vec<usize> = visitor.names.iter().map(|(_, depth)| *depth).collect();
+        let mut sorted_depths = depths.clone();
+        sorted_depths.sort();
+        assert_eq!(depths, sorted_depths);
+    }
+}
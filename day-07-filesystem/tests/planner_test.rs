@@ -0,0 +1,111 @@
+mod common;
+
+mod get_smallest_fitting_directory {
+    use crate::common::build_file_system;
+    use day_07_filesystem::Device;
+
+    #[test]
+    fn test_default_device() {
+        // Given
+        let file_system = build_file_system();
+        let device = Device::default();
+
+        // When
+        let result = device.get_smallest_fitting_directory(&file_system);
+
+        // Then
+        assert_eq!(result, file_system.find_directory("/d"));
+    }
+
+    #[test]
+    fn test_custom_device() {
+        // Given
+        let file_system = build_file_system();
+        let device = Device::new(48381165, 90000);
+
+        // When
+        let result = device.get_smallest_fitting_directory(&file_system);
+
+        // Then
+        assert_eq!(device.get_missing_space(&file_system), 90000);
+        assert_eq!(result, file_system.find_directory("/a"));
+    }
+}
+
+mod plan_deletion {
+    use crate::common::{build_file_system, file};
+    use day_07_filesystem::{Deletion, Device, FileSystem};
+
+    #[test]
+    fn test_single_file() {
+        // Given
+        let file_system = build_file_system();
+        let device = Device::default();
+
+        // When
+        let result = device.plan_deletion(&file_system).unwrap();
+
+        // Then
+        assert_eq!(result.freed_space, 8504156);
+        assert_eq!(
+            result.deletions,
+            vec![Deletion::File(
+                file_system.get_root_id(),
+                "c.dat".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_whole_directory() {
+        // Given
+        let file_system = build_file_system();
+        let device = Device::new(48381165, 94853);
+
+        // When
+        let result = device.plan_deletion(&file_system).unwrap();
+
+        // Then
+        assert_eq!(result.freed_space, 94853);
+        assert_eq!(
+            result.deletions,
+            vec![Deletion::Directory(
+                file_system.find_directory("/a").unwrap()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_files_across_directories() {
+        // Given
+        let mut file_system = FileSystem::new();
+        let root = file_system.get_root_id();
+        let a = file_system.add_directory(root, "a".to_string());
+        let b = file_system.add_directory(root, "b".to_string());
+        let c = file_system.add_directory(b, "c".to_string());
+        file_system.add_file(a, file("x", 50));
+        file_system.add_file(a, file("y", 36));
+        file_system.add_file(b, file("z", 60));
+        file_system.add_file(c, file("w", 15));
+        file_system.add_directory(c, "empty".to_string());
+        let device = Device::new(161, 50);
+
+        // When
+        let result = device.plan_deletion(&file_system).unwrap();
+
+        // Then
+        assert_eq!(result.freed_space, 50);
+        assert_eq!(result.deletions, vec![Deletion::File(a, "x".to_string())]);
+
+        // When
+        let device = Device::new(161, 100);
+        let result = device.plan_deletion(&file_system).unwrap();
+
+        // Then
+        assert_eq!(result.freed_space, 101);
+        assert_eq!(
+            result.deletions,
+            vec![Deletion::Directory(a), Deletion::Directory(c)]
+        );
+    }
+}
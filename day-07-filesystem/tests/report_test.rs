@@ -0,0 +1,108 @@
+mod common;
+
+mod render {
+    use crate::common::build_file_system;
+    use day_07_filesystem::{render_du, render_tree, to_json};
+
+    #[test]
+    fn test_tree() {
+        // Given
+        let file_system = build_file_system();
+
+        // When
+        let result = render_tree(&file_system);
+
+        // Then
+        assert_eq!(
+            result,
+            "/ (47M)
+├── a/ (93K)
+│   ├── e/ (584)
+│   │   └── i (584)
+│   ├── f (29K)
+│   ├── g (2.5K)
+│   └── h.lst (62K)
+├── b.txt (15M)
+├── c.dat (8.2M)
+└── d/ (24M)
+    ├── d.ext (5.4M)
+    ├── d.log (7.7M)
+    ├── j (3.9M)
+    └── k (6.9M)"
+        );
+    }
+
+    #[test]
+    fn test_du() {
+        // Given
+        let file_system = build_file_system();
+
+        // When
+        let result = render_du(&file_system);
+
+        // Then
+        assert_eq!(result, "584\t/a/e\n93K\t/a\n24M\t/d\n47M\t/");
+    }
+
+    #[test]
+    fn test_json() {
+        // Given
+        let file_system = build_file_system();
+
+        // When
+        let result = to_json(&file_system);
+
+        // Then
+        assert!(result.starts_with(
+            "{\"name\":\"/\",\"type\":\"directory\",\"size\":48381165,\"children\":[{\"name\":\"a\""
+        ));
+        assert!(result.contains(
+            "{\"name\":\"e\",\"type\":\"directory\",\"size\":584,\"children\":[{\"name\":\"i\",\"type\":\"file\",\"size\":584}]}"
+        ));
+    }
+}
+
+mod format_size {
+    use day_07_filesystem::format_size;
+
+    #[test]
+    fn test_units() {
+        assert_eq!(format_size(0), "0");
+        assert_eq!(format_size(1023), "1023");
+        assert_eq!(format_size(1024), "1.0K");
+        assert_eq!(format_size(1025), "1.1K");
+        assert_eq!(format_size(10 * 1024), "10K");
+        assert_eq!(format_size(3 * 1024 * 1024 * 1024), "3.0G");
+    }
+}
+
+mod queries {
+    use crate::common::build_file_system;
+    use day_07_filesystem::{get_directories_under, get_largest_directories};
+
+    #[test]
+    fn test_largest_directories() {
+        // Given
+        let file_system = build_file_system();
+
+        // When
+        let result = get_largest_directories(&file_system, 2);
+
+        // Then
+        let paths: Vec<String> = result.iter().map(|id| file_system.get_path(*id)).collect();
+        assert_eq!(paths, vec!["/", "/d"]);
+    }
+
+    #[test]
+    fn test_directories_under() {
+        // Given
+        let file_system = build_file_system();
+
+        // When
+        let result = get_directories_under(&file_system, 100000);
+
+        // Then
+        let paths: Vec<String> = result.iter().map(|id| file_system.get_path(*id)).collect();
+        assert_eq!(paths, vec!["/a", "/a/e"]);
+    }
+}
@@ -0,0 +1,245 @@
+use day_07_filesystem::{Line, Session};
+
+mod parse {
+    use day_07_filesystem::{Command, DirectoryMove};
+
+    #[test]
+    fn test_cd_multi_segment() {
+        // Given
+        let line = "$ cd /a/../b/./c";
+
+        // When
+        let result = Command::parse(line);
+
+        // Then
+        assert_eq!(
+            result,
+            Command::Cd(vec![
+                DirectoryMove::Root,
+                DirectoryMove::In("a".to_string()),
+                DirectoryMove::Out,
+                DirectoryMove::In("b".to_string()),
+                DirectoryMove::In("c".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_rm_with_flags() {
+        // Given
+        let line = "$ rm -rf a/b c";
+
+        // When
+        let result = Command::parse(line);
+
+        // Then
+        assert_eq!(
+            result,
+            Command::Rm(vec!["a/b".to_string(), "c".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_unknown_command() {
+        // Given
+        let line = "$ cat a.txt";
+
+        // When
+        let result = Command::parse(line);
+
+        // Then
+        assert_eq!(result, Command::Other("cat".to_string()));
+    }
+}
+
+mod replay {
+    use crate::replay;
+
+    #[test]
+    fn test_repeated_listing() {
+        // Given
+        let lines = [
+            "$ cd /", "$ ls", "dir a", "10 b.txt", "$ ls", "dir a", "10 b.txt", "20 c.txt",
+        ];
+
+        // When
+        let session = replay(&lines);
+
+        // Then
+        let file_system = &session.file_system;
+        assert_eq!(file_system.get_size(file_system.get_root_id()), 30);
+        assert_eq!(
+            file_system
+                .get_directory(file_system.get_root_id())
+                .children
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_repeated_listing_with_new_size() {
+        // Given
+        let lines = ["$ ls", "10 b.txt", "$ ls", "15 b.txt"];
+
+        // When
+        let session = replay(&lines);
+
+        // Then
+        let file_system = &session.file_system;
+        assert_eq!(file_system.get_size(file_system.get_root_id()), 15);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inconsistent_listing() {
+        // Given
+        let lines = ["$ ls", "10 a", "$ ls", "dir a"];
+
+        // When
+        replay(&lines);
+    }
+
+    #[test]
+    fn test_cd_multi_segment() {
+        // Given
+        let lines = [
+            "$ mkdir -p a/b/c",
+            "$ cd a/b/c",
+            "$ pwd",
+            "/a/b/c",
+            "$ cd ../../..",
+            "$ pwd",
+            "/",
+            "$ cd /a/b",
+            "$ pwd",
+            "/a/b",
+        ];
+
+        // When
+        let session = replay(&lines);
+
+        // Then
+        let file_system = &session.file_system;
+        assert_eq!(file_system.get_path(session.get_cd()), "/a/b");
+    }
+
+    #[test]
+    #[should_panic(expected = "The directory / do not have a child a")]
+    fn test_cd_into_unlisted_directory() {
+        // Given
+        let lines = ["$ cd /", "$ ls", "dir b", "$ cd a"];
+
+        // When
+        replay(&lines);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot change directory to a as it is a file")]
+    fn test_cd_into_file() {
+        // Given
+        let lines = ["$ ls", "10 a", "$ cd a"];
+
+        // When
+        replay(&lines);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inconsistent_pwd() {
+        // Given
+        let lines = ["$ mkdir a", "$ cd a", "$ pwd", "/b"];
+
+        // When
+        replay(&lines);
+    }
+
+    #[test]
+    fn test_rm() {
+        // Given
+        let lines = [
+            "$ ls",
+            "dir a",
+            "10 b.txt",
+            "$ cd a",
+            "$ ls",
+            "20 c.txt",
+            "30 d.txt",
+            "$ rm c.txt",
+            "$ cd /",
+            "$ rm -f missing.txt",
+        ];
+
+        // When
+        let session = replay(&lines);
+
+        // Then
+        let file_system = &session.file_system;
+        assert_eq!(file_system.get_size(file_system.get_root_id()), 40);
+        assert!(file_system.find_entry("/a/c.txt").is_none());
+    }
+
+    #[test]
+    fn test_rm_directory() {
+        // Given
+        let lines = [
+            "$ ls",
+            "dir a",
+            "10 b.txt",
+            "$ cd a",
+            "$ ls",
+            "20 c.txt",
+            "$ cd ..",
+            "$ rm -r a",
+        ];
+
+        // When
+        let session = replay(&lines);
+
+        // Then
+        let file_system = &session.file_system;
+        assert_eq!(file_system.get_size(file_system.get_root_id()), 10);
+        assert_eq!(file_system.directories().count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot remove `/a` as it contains the current directory")]
+    fn test_rm_current_directory() {
+        // Given
+        let lines = ["$ cd /", "$ mkdir a", "$ cd a", "$ rm /a", "$ ls", "10 f"];
+
+        // When
+        replay(&lines);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot remove `/a` as it contains the current directory")]
+    fn test_rm_ancestor_directory() {
+        // Given
+        let lines = ["$ mkdir -p a/b", "$ cd a/b", "$ rm -r ../../a"];
+
+        // When
+        replay(&lines);
+    }
+
+    #[test]
+    fn test_unknown_command_output_is_ignored() {
+        // Given
+        let lines = ["$ ls", "10 b.txt", "$ cat b.txt", "hello world"];
+
+        // When
+        let session = replay(&lines);
+
+        // Then
+        let file_system = &session.file_system;
+        assert_eq!(file_system.get_size(file_system.get_root_id()), 10);
+    }
+}
+
+fn replay(lines: &[&str]) -> Session {
+    let mut session = Session::new();
+    lines
+        .iter()
+        .for_each(|line| session.process_line(Line::parse(line)));
+    session
+}
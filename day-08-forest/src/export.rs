@@ -0,0 +1,123 @@
+use grid::{Grid, Pos};
+
+use crate::forest::{Forest, ScenicScore};
+
+const MAX_COLOR_VALUE: usize = 255;
+const BEST_TREE_COLOR: Color = (0, 255, 0);
+const ANSI_RESET: &str = "\x1b[0m";
+
+type Color = (usize, usize, usize);
+
+impl Forest {
+    pub fn get_best_tree(&self) -> Option<(Pos, ScenicScore)> {
+        get_best_position(&self.get_scenic_scores())
+    }
+
+    fn get_colors(&self) -> (Grid<Color>, Option<Pos>) {
+        let visibility_map = self.get_visibility_map();
+        let scores = self.get_scenic_scores();
+        let best_tree = get_best_position(&scores);
+        let max_score = best_tree.map_or(0, |(_, score)| score);
+        let mut colors = scores.map(|score| {
+            let heat = scale(*score, max_score);
+            (heat, 0, MAX_COLOR_VALUE - heat)
+        });
+        visibility_map.iter().for_each(|(pos, visible)| {
+            if !visible {
+                let (red, green, blue) = colors[&pos];
+                colors[&pos] = (red / 2, green / 2, blue / 2);
+            }
+        });
+        let best_tree = best_tree.map(|(pos, _)| pos);
+        if let Some(pos) = best_tree {
+            colors[&pos] = BEST_TREE_COLOR;
+        }
+        (colors, best_tree)
+    }
+
+    pub fn to_ppm(&self) -> String {
+        let (colors, _) = self.get_colors();
+        let pixels: Vec<String> = colors
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|(red, green, blue)| format!("{} {} {}", red, green, blue))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect();
+        format!(
+            "P3\n{} {}\n{}\n{}\n",
+            colors.get_width(),
+            colors.get_height(),
+            MAX_COLOR_VALUE,
+            pixels.join("\n")
+        )
+    }
+
+    // Grayscale image of the scenic scores, the best tree is white
+    pub fn to_pgm(&self) -> String {
+        let values = self.get_scenic_scores();
+        let max_value = values.iter().map(|(_, value)| *value).max().unwrap_or(0);
+        let pixels: Vec<String> = values
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|value| scale(*value, max_value).to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect();
+        format!(
+            "P2\n{} {}\n{}\n{}\n",
+            values.get_width(),
+            values.get_height(),
+            MAX_COLOR_VALUE,
+            pixels.join("\n")
+        )
+    }
+
+    pub fn render_heatmap(&self) -> String {
+        let (colors, best_tree) = self.get_colors();
+        colors
+            .rows()
+            .enumerate()
+            .map(|(y, row)| {
+                let cells: String = row
+                    .iter()
+                    .enumerate()
+                    .map(|(x, (red, green, blue))| {
+                        let pos = Pos::new(x, y);
+                        let weight = match Some(pos) == best_tree {
+                            true => "1;",
+                            false => "",
+                        };
+                        format!(
+                            "\x1b[{}38;2;0;0;0;48;2;{};{};{}m{}",
+                            weight, red, green, blue, self.trees[&pos]
+                        )
+                    })
+                    .collect();
+                format!("{}{}", cells, ANSI_RESET)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+fn get_best_position(scores: &Grid<ScenicScore>) -> Option<(Pos, ScenicScore)> {
+    scores
+        .iter()
+        .map(|(pos, score)| (pos, *score))
+        .reduce(|best, current| match current.1 > best.1 {
+            true => current,
+            false => best,
+        })
+}
+
+fn scale(value: usize, max_value: usize) -> usize {
+    match max_value {
+        0 => 0,
+        _ => value * MAX_COLOR_VALUE / max_value,
+    }
+}
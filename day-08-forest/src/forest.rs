@@ -0,0 +1,103 @@
+use grid::{Direction, Grid, Pos};
+
+pub type TreeSize = u8;
+pub type ScenicScore = usize;
+
+pub struct Forest {
+    pub trees: Grid<TreeSize>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Position {
+    pub column_idx: usize,
+    pub row_idx: usize,
+}
+
+impl Position {
+    pub fn to_pos(&self) -> Pos {
+        Pos::new(self.column_idx, self.row_idx)
+    }
+
+    pub fn from_pos(pos: &Pos) -> Self {
+        Position {
+            column_idx: pos.x,
+            row_idx: pos.y,
+        }
+    }
+}
+
+impl Default for Forest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Forest {
+    pub fn new() -> Self {
+        Self {
+            trees: Grid::default(),
+        }
+    }
+
+    pub fn add_line(&mut self, line: &str) {
+        let line: Vec<TreeSize> = line
+            .chars()
+            .map(|char| u8::try_from(char.to_digit(10).unwrap()).unwrap())
+            .collect();
+        self.trees.push_row(line);
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.trees.get_height()
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.trees.get_width()
+    }
+
+    fn get_trees_towards(
+        &self,
+        pos: &Position,
+        direction: Direction,
+    ) -> impl Iterator<Item = &TreeSize> {
+        self.trees
+            .ray(&pos.to_pos(), direction)
+            .map(|(_, tree_size)| tree_size)
+    }
+
+    pub fn is_tree_visible(&self, pos: &Position) -> bool {
+        let tree_size = self.get_tree_size(pos);
+        Direction::ORTHOGONAL.iter().any(|direction| {
+            self.get_trees_towards(pos, *direction)
+                .all(|adjacent_size| *adjacent_size < tree_size)
+        })
+    }
+
+    pub fn get_tree_scenic_score(&self, pos: &Position) -> ScenicScore {
+        let tree_size = self.get_tree_size(pos);
+        Direction::ORTHOGONAL
+            .iter()
+            .map(|direction| {
+                let mut count = 0;
+                let result = self.get_trees_towards(pos, *direction).enumerate().find(
+                    |(_, adjacent_size)| {
+                        count += 1;
+                        **adjacent_size >= tree_size
+                    },
+                );
+                match result {
+                    Some((nb_trees, _)) => nb_trees + 1,
+                    None => count,
+                }
+            })
+            .product()
+    }
+
+    pub fn get_tree_size(&self, pos: &Position) -> TreeSize {
+        self.trees[&pos.to_pos()]
+    }
+
+    pub fn get_trees_iter(&self) -> impl Iterator<Item = Position> + '_ {
+        self.trees.positions().map(|pos| Position::from_pos(&pos))
+    }
+}
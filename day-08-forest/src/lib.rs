@@ -0,0 +1,7 @@
+mod export;
+mod forest;
+mod line_of_sight;
+mod sweep;
+
+pub use forest::{Forest, Position, ScenicScore, TreeSize};
+pub use sweep::ViewingDistances;
@@ -0,0 +1,64 @@
+use crate::forest::{Forest, Position, TreeSize};
+
+impl Position {
+    pub fn get_line_to(&self, to: &Position) -> Vec<Position> {
+        let (x0, y0) = (self.column_idx as isize, self.row_idx as isize);
+        let (x1, y1) = (to.column_idx as isize, to.row_idx as isize);
+        let delta_x = (x1 - x0).abs();
+        let delta_y = -(y1 - y0).abs();
+        let step_x = (x1 - x0).signum();
+        let step_y = (y1 - y0).signum();
+
+        let mut line = Vec::new();
+        let (mut x, mut y) = (x0, y0);
+        let mut error = delta_x + delta_y;
+        loop {
+            line.push(Position {
+                column_idx: x as usize,
+                row_idx: y as usize,
+            });
+            if x == x1 && y == y1 {
+                return line;
+            }
+            let double_error = 2 * error;
+            if double_error >= delta_y {
+                error += delta_y;
+                x += step_x;
+            }
+            if double_error <= delta_x {
+                error += delta_x;
+                y += step_y;
+            }
+        }
+    }
+}
+
+impl Forest {
+    // The sight goes from the eye of the observer to the top of the target tree, and any tree
+    // reaching that line of sight in between hides the target.
+    pub fn is_visible_from(&self, from: &Position, eye_height: TreeSize, to: &Position) -> bool {
+        let line = from.get_line_to(to);
+        let steps = line.len() as isize - 1;
+        if steps <= 0 {
+            return true;
+        }
+        let eye_height = eye_height as isize;
+        let target_height = self.get_tree_size(to) as isize;
+        line.iter()
+            .enumerate()
+            .skip(1)
+            .take(steps as usize - 1)
+            .all(|(step, pos)| {
+                let tree_height = self.get_tree_size(pos) as isize;
+                let sight_height =
+                    eye_height * steps + (target_height - eye_height) * step as isize;
+                tree_height * steps < sight_height
+            })
+    }
+
+    pub fn count_visible_from(&self, from: &Position, eye_height: TreeSize) -> usize {
+        self.get_trees_iter()
+            .filter(|pos| pos != from && self.is_visible_from(from, eye_height, pos))
+            .count()
+    }
+}
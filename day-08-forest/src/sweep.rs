@@ -0,0 +1,127 @@
+use grid::{Grid, Pos};
+
+use crate::forest::{Forest, ScenicScore, TreeSize};
+
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct ViewingDistances {
+    pub top: usize,
+    pub bottom: usize,
+    pub left: usize,
+    pub right: usize,
+}
+
+impl ViewingDistances {
+    pub fn get_scenic_score(&self) -> ScenicScore {
+        self.top * self.bottom * self.left * self.right
+    }
+}
+
+enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Forest {
+    // Each line is walked from the given side, so that every tree only looks at the trees
+    // already seen: the running maximum tells whether it is visible from that side, and a
+    // stack of decreasing sizes gives the closest blocking tree.
+    fn get_lines(&self, side: &Side) -> Vec<Vec<Pos>> {
+        let height = self.get_height();
+        let width = self.get_width();
+        let position = |row_idx, column_idx| Pos::new(column_idx, row_idx);
+        match side {
+            Side::Left => (0..height)
+                .map(|row_idx| {
+                    (0..width)
+                        .map(|column_idx| position(row_idx, column_idx))
+                        .collect()
+                })
+                .collect(),
+            Side::Right => (0..height)
+                .map(|row_idx| {
+                    (0..width)
+                        .rev()
+                        .map(|column_idx| position(row_idx, column_idx))
+                        .collect()
+                })
+                .collect(),
+            Side::Top => (0..width)
+                .map(|column_idx| {
+                    (0..height)
+                        .map(|row_idx| position(row_idx, column_idx))
+                        .collect()
+                })
+                .collect(),
+            Side::Bottom => (0..width)
+                .map(|column_idx| {
+                    (0..height)
+                        .rev()
+                        .map(|row_idx| position(row_idx, column_idx))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    pub fn get_visibility_map(&self) -> Grid<bool> {
+        let mut visibility_map = Grid::new(self.get_width(), self.get_height(), false);
+        [Side::Top, Side::Bottom, Side::Left, Side::Right]
+            .iter()
+            .flat_map(|side| self.get_lines(side))
+            .for_each(|line| {
+                let mut highest: Option<TreeSize> = None;
+                line.iter().for_each(|pos| {
+                    let tree_size = self.trees[pos];
+                    if highest.is_none_or(|highest| tree_size > highest) {
+                        visibility_map[pos] = true;
+                        highest = Some(tree_size);
+                    }
+                });
+            });
+        visibility_map
+    }
+
+    pub fn get_viewing_distances(&self) -> Grid<ViewingDistances> {
+        let mut distances_map = Grid::new(
+            self.get_width(),
+            self.get_height(),
+            ViewingDistances::default(),
+        );
+        [Side::Top, Side::Bottom, Side::Left, Side::Right]
+            .iter()
+            .for_each(|side| {
+                self.get_lines(side).iter().for_each(|line| {
+                    let mut stack: Vec<(usize, TreeSize)> = Vec::new();
+                    line.iter().enumerate().for_each(|(idx, pos)| {
+                        let tree_size = self.trees[pos];
+                        while let Some((_, blocking_size)) = stack.last() {
+                            if *blocking_size >= tree_size {
+                                break;
+                            }
+                            stack.pop();
+                        }
+                        let distance = match stack.last() {
+                            Some((blocking_idx, _)) => idx - blocking_idx,
+                            None => idx,
+                        };
+                        stack.push((idx, tree_size));
+                        let distances = &mut distances_map[pos];
+                        match side {
+                            Side::Top => distances.top = distance,
+                            Side::Bottom => distances.bottom = distance,
+                            Side::Left => distances.left = distance,
+                            Side::Right => distances.right = distance,
+                        }
+                    });
+                });
+            });
+        distances_map
+    }
+
+    pub fn get_scenic_scores(&self) -> Grid<ScenicScore> {
+        self.get_viewing_distances()
+            .map(|distances| distances.get_scenic_score())
+    }
+}
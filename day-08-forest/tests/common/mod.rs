@@ -0,0 +1,16 @@
+// Shared by several test crates, each of them only uses part of it
+#![allow(dead_code)]
+
+use day_08_forest::Forest;
+
+pub const EXAMPLE: [&str; 5] = ["30373", "25512", "65332", "33549", "35390"];
+
+pub fn build_forest() -> Forest {
+    parse_forest(&EXAMPLE)
+}
+
+pub fn parse_forest(lines: &[&str]) -> Forest {
+    let mut forest = Forest::new();
+    lines.iter().for_each(|line| forest.add_line(line));
+    forest
+}
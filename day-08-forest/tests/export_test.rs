@@ -0,0 +1,83 @@
+mod common;
+
+mod get_best_tree {
+    use crate::common::build_forest;
+    use grid::Pos;
+
+    #[test]
+    fn test_example() {
+        // Given
+        let forest = build_forest();
+
+        // When
+        let result = forest.get_best_tree();
+
+        // Then
+        assert_eq!(result, Some((Pos::new(2, 3), 8)));
+    }
+}
+
+mod to_pgm {
+    use crate::common::build_forest;
+
+    #[test]
+    fn test_example() {
+        // Given
+        let forest = build_forest();
+
+        // When
+        let result = forest.to_pgm();
+
+        // Then
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "P2");
+        assert_eq!(lines[1], "5 5");
+        assert_eq!(lines[2], "255");
+        assert_eq!(lines[3], "0 0 0 0 0");
+        assert_eq!(lines[4], "0 31 127 31 0");
+        assert_eq!(lines[6], "0 31 255 95 0");
+    }
+}
+
+mod to_ppm {
+    use crate::common::build_forest;
+
+    #[test]
+    fn test_example() {
+        // Given
+        let forest = build_forest();
+
+        // When
+        let result = forest.to_ppm();
+
+        // Then
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0..3], ["P3", "5 5", "255"]);
+        assert_eq!(lines.len(), 8);
+        let best_row: Vec<&str> = lines[6].split(' ').collect();
+        assert_eq!(best_row[6..9], ["0", "255", "0"]);
+        let hidden_row: Vec<&str> = lines[5].split(' ').collect();
+        assert_eq!(hidden_row[6..9], ["15", "0", "112"]);
+    }
+}
+
+mod render_heatmap {
+    use crate::common::build_forest;
+
+    #[test]
+    fn test_example() {
+        // Given
+        let forest = build_forest();
+
+        // When
+        let result = forest.render_heatmap();
+
+        // Then
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines
+            .iter()
+            .all(|line| line.starts_with("\x1b[") && line.ends_with("\x1b[0m")));
+        assert!(lines[3].contains("\x1b[1;38;2;0;0;0;48;2;0;255;0m5"));
+    }
+}
@@ -0,0 +1,125 @@
+mod common;
+
+use day_08_forest::Position;
+
+mod get_line_to {
+    use crate::{get_positions_array, position};
+
+    #[test]
+    fn test_horizontal() {
+        // Given
+        let from = position(0, 0);
+
+        // When
+        let result = from.get_line_to(&position(3, 0));
+
+        // Then
+        assert_eq!(
+            get_positions_array(&result),
+            vec![(0, 0), (1, 0), (2, 0), (3, 0)]
+        );
+    }
+
+    #[test]
+    fn test_diagonal() {
+        // Given
+        let from = position(2, 2);
+
+        // When
+        let result = from.get_line_to(&position(0, 0));
+
+        // Then
+        assert_eq!(get_positions_array(&result), vec![(2, 2), (1, 1), (0, 0)]);
+    }
+
+    #[test]
+    fn test_slope() {
+        // Given
+        let from = position(0, 0);
+
+        // When
+        let result = from.get_line_to(&position(4, 2));
+
+        // Then
+        assert_eq!(
+            get_positions_array(&result),
+            vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]
+        );
+    }
+}
+
+mod is_visible_from {
+    use crate::common::build_forest;
+    use crate::position;
+
+    #[test]
+    fn test_along_column() {
+        // Given
+        let forest = build_forest();
+        let observer = position(2, 3);
+
+        // When
+        let blocking_tree = forest.is_visible_from(&observer, 5, &position(2, 1));
+        let hidden_tree = forest.is_visible_from(&observer, 5, &position(2, 0));
+
+        // Then
+        assert!(blocking_tree);
+        assert!(!hidden_tree);
+    }
+
+    #[test]
+    fn test_higher_eye() {
+        // Given
+        let forest = build_forest();
+        let observer = position(2, 3);
+
+        // When
+        let result = forest.is_visible_from(&observer, 10, &position(2, 0));
+
+        // Then
+        assert!(result);
+    }
+}
+
+mod count_visible_from {
+    use crate::common::parse_forest;
+    use crate::position;
+
+    #[test]
+    fn test_blocked_by_center() {
+        // Given
+        let forest = parse_forest(&["111", "191", "111"]);
+
+        // When
+        let result = forest.count_visible_from(&position(0, 0), 1);
+
+        // Then
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn test_above_the_canopy() {
+        // Given
+        let forest = parse_forest(&["111", "191", "111"]);
+
+        // When
+        let result = forest.count_visible_from(&position(0, 0), 10);
+
+        // Then
+        assert_eq!(result, 5);
+    }
+}
+
+fn position(column_idx: usize, row_idx: usize) -> Position {
+    Position {
+        column_idx,
+        row_idx,
+    }
+}
+
+fn get_positions_array(positions: &[Position]) -> Vec<(usize, usize)> {
+    positions
+        .iter()
+        .map(|pos| (pos.column_idx, pos.row_idx))
+        .collect()
+}
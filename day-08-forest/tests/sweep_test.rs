@@ -0,0 +1,117 @@
+mod common;
+
+mod get_visibility_map {
+    use crate::common::build_forest;
+
+    #[test]
+    fn test_example() {
+        // Given
+        let forest = build_forest();
+
+        // When
+        let result = forest.get_visibility_map();
+
+        // Then
+        let count = result.iter().filter(|(_, visible)| **visible).count();
+        assert_eq!(count, 21);
+        assert_eq!(result.row(1), &[true, true, true, false, true]);
+        assert_eq!(result.row(2), &[true, true, false, true, true]);
+        assert_eq!(result.row(3), &[true, false, true, false, true]);
+    }
+
+    #[test]
+    fn test_matches_per_tree_reference() {
+        // Given
+        let forest = build_forest();
+
+        // When
+        let result = forest.get_visibility_map();
+
+        // Then
+        forest.get_trees_iter().for_each(|pos| {
+            assert_eq!(
+                result[&pos.to_pos()],
+                forest.is_tree_visible(&pos),
+                "{:?}",
+                pos
+            )
+        });
+    }
+}
+
+mod get_viewing_distances {
+    use crate::common::build_forest;
+    use day_08_forest::{Forest, ViewingDistances};
+    use grid::Pos;
+
+    #[test]
+    fn test_example() {
+        // Given
+        let forest = build_forest();
+
+        // When
+        let result = forest.get_viewing_distances();
+
+        // Then
+        assert_eq!(
+            result[&Pos::new(2, 1)],
+            ViewingDistances {
+                top: 1,
+                bottom: 2,
+                left: 1,
+                right: 2,
+            }
+        );
+        assert_eq!(
+            result[&Pos::new(2, 3)],
+            ViewingDistances {
+                top: 2,
+                bottom: 1,
+                left: 2,
+                right: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_matches_per_tree_reference() {
+        // Given
+        let forest = build_forest();
+
+        // When
+        let result = forest.get_scenic_scores();
+
+        // Then
+        forest.get_trees_iter().for_each(|pos| {
+            assert_eq!(
+                result[&pos.to_pos()],
+                forest.get_tree_scenic_score(&pos),
+                "{:?}",
+                pos
+            )
+        });
+        assert_eq!(result.iter().map(|(_, score)| *score).max(), Some(8));
+    }
+
+    #[test]
+    fn test_rectangular_forest() {
+        // Given
+        let mut forest = Forest::new();
+        forest.add_line("3037");
+        forest.add_line("2551");
+        forest.add_line("6533");
+
+        // When
+        let result = forest.get_scenic_scores();
+
+        // Then
+        forest.get_trees_iter().for_each(|pos| {
+            assert_eq!(
+                result[&pos.to_pos()],
+                forest.get_tree_scenic_score(&pos),
+                "{:?}",
+                pos
+            )
+        });
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+
+use crate::coordinate::{Coordinate, Position};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    pub fn parse(text: &str) -> Self {
+        match text {
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "UL" | "LU" => Direction::UpLeft,
+            "UR" | "RU" => Direction::UpRight,
+            "DL" | "LD" => Direction::DownLeft,
+            "DR" | "RD" => Direction::DownRight,
+            _ => panic!("Invalid text for direction: `{}`", text),
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Direction::Up => "U",
+            Direction::Down => "D",
+            Direction::Left => "L",
+            Direction::Right => "R",
+            Direction::UpLeft => "UL",
+            Direction::UpRight => "UR",
+            Direction::DownLeft => "DL",
+            Direction::DownRight => "DR",
+        }
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        !matches!(
+            self,
+            Direction::Up | Direction::Down | Direction::Left | Direction::Right
+        )
+    }
+
+    pub fn get_offset<T: Coordinate>(&self) -> Position<T> {
+        let (x, y) = match self {
+            Direction::Up => (0, 1),
+            Direction::Down => (0, -1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+            Direction::UpLeft => (-1, 1),
+            Direction::UpRight => (1, 1),
+            Direction::DownLeft => (-1, -1),
+            Direction::DownRight => (1, -1),
+        };
+        Position {
+            x: T::from_offset(x),
+            y: T::from_offset(y),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Command {
+    pub direction: Direction,
+    pub nb_steps: usize,
+}
+
+impl Command {
+    pub fn parse(text: &str) -> Self {
+        let mut words = text.split(' ');
+
+        let direction = Direction::parse(words.next().unwrap());
+        let nb_steps = words
+            .next()
+            .unwrap_or_else(|| panic!("Missing number of steps in command: `{}`", text))
+            .parse()
+            .unwrap();
+        Command {
+            direction,
+            nb_steps,
+        }
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.direction.get_name(), self.nb_steps)
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+pub type PosUnit = i32;
+
+pub trait Coordinate: Copy + Ord + Hash + Debug {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn from_offset(offset: i8) -> Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn signum(self) -> Self;
+    fn split_chunk(self, chunk_size: usize) -> (Self, usize);
+    fn join_chunk(chunk: Self, local: usize, chunk_size: usize) -> Self;
+}
+
+macro_rules! impl_coordinate {
+    ($($unit:ty),*) => {
+        $(
+            impl Coordinate for $unit {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn from_offset(offset: i8) -> Self {
+                    Self::from(offset)
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$unit>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$unit>::checked_sub(self, other)
+                }
+
+                fn signum(self) -> Self {
+                    <$unit>::signum(self)
+                }
+
+                fn split_chunk(self, chunk_size: usize) -> (Self, usize) {
+                    let chunk_size = chunk_size as Self;
+                    (self.div_euclid(chunk_size), self.rem_euclid(chunk_size) as usize)
+                }
+
+                fn join_chunk(chunk: Self, local: usize, chunk_size: usize) -> Self {
+                    chunk * chunk_size as Self + local as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i16, i32, i64, isize);
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Position<T: Coordinate = PosUnit> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Position<T> {
+    pub fn origin() -> Self {
+        Position {
+            x: T::ZERO,
+            y: T::ZERO,
+        }
+    }
+
+    pub fn is_too_far(&self) -> bool {
+        let one = T::ONE;
+        let minus_one = T::from_offset(-1);
+        self.x > one || self.x < minus_one || self.y > one || self.y < minus_one
+    }
+
+    pub fn sub(&self, b: &Self) -> Self {
+        let x = self.x.checked_sub(b.x);
+        let y = self.y.checked_sub(b.y);
+        match (x, y) {
+            (Some(x), Some(y)) => Position { x, y },
+            _ => panic!("Coordinate overflow computing `{:?} - {:?}`", self, b),
+        }
+    }
+
+    pub fn get_catchup_move(&self) -> Self {
+        Position {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
+    pub fn apply_diff(&mut self, diff: &Self) {
+        let x = self.x.checked_add(diff.x);
+        let y = self.y.checked_add(diff.y);
+        match (x, y) {
+            (Some(x), Some(y)) => {
+                self.x = x;
+                self.y = y;
+            }
+            _ => panic!("Coordinate overflow moving `{:?}` by `{:?}`", self, diff),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod command;
+mod coordinate;
+mod render;
+mod rope;
+mod visited;
+
+pub use command::{Command, Direction};
+pub use coordinate::{Coordinate, PosUnit, Position};
+pub use render::{get_knot_label, get_simulation_bounds, render_frames, Bounds, Frame, FrameStep};
+pub use rope::{simulate, simulate_as, Rope, RopeConfig, LONG_ROPE_SIZE, ROPE_SIZE};
+pub use visited::VisitedSet;
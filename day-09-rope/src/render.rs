@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::command::Command;
+use crate::coordinate::{Coordinate, PosUnit, Position};
+use crate::rope::{Rope, RopeConfig};
+
+const EMPTY_CELL: char = '.';
+const VISITED_CELL: char = '#';
+const START_CELL: char = 's';
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Bounds<T: Coordinate = PosUnit> {
+    pub min: Position<T>,
+    pub max: Position<T>,
+}
+
+impl<T: Coordinate> Default for Bounds<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Coordinate> Bounds<T> {
+    pub fn new() -> Self {
+        Bounds {
+            min: Position::origin(),
+            max: Position::origin(),
+        }
+    }
+
+    pub fn include(&mut self, pos: &Position<T>) {
+        self.min.x = self.min.x.min(pos.x);
+        self.min.y = self.min.y.min(pos.y);
+        self.max.x = self.max.x.max(pos.x);
+        self.max.y = self.max.y.max(pos.y);
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        self.include(&other.min);
+        self.include(&other.max);
+    }
+}
+
+pub enum FrameStep {
+    Command,
+    Step,
+}
+
+impl FrameStep {
+    pub fn parse(word: &str) -> Self {
+        match word {
+            "commands" => FrameStep::Command,
+            "steps" => FrameStep::Step,
+            _ => panic!("Invalid frame step: `{}`", word),
+        }
+    }
+}
+
+pub struct Frame {
+    pub label: String,
+    pub image: String,
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "== {} ==\n\n{}", self.label, self.image)
+    }
+}
+
+pub fn get_knot_label(knot_idx: usize, length: usize) -> char {
+    match (knot_idx, length) {
+        (0, _) => 'H',
+        (1, 2) => 'T',
+        _ => char::from_digit(knot_idx as u32, 36).unwrap_or('*'),
+    }
+}
+
+impl<T: Coordinate> Rope<T> {
+    pub fn get_bounds(&self) -> Bounds<T> {
+        let mut bounds = Bounds::new();
+        self.get_knots()
+            .iter()
+            .for_each(|knot| bounds.include(knot));
+        if let Some(visited) = self.get_last_tracked_visited() {
+            visited.iter().for_each(|pos| bounds.include(&pos));
+        }
+        bounds
+    }
+
+    pub fn render(&self) -> String {
+        self.render_within(&self.get_bounds())
+    }
+
+    pub fn render_within(&self, bounds: &Bounds<T>) -> String {
+        let knots = self.get_knots();
+        let mut knot_by_pos = HashMap::new();
+        knots.iter().enumerate().rev().for_each(|(knot_idx, knot)| {
+            knot_by_pos.insert(knot, knot_idx);
+        });
+        let visited = self.get_last_tracked_visited();
+
+        get_range(bounds.min.y, bounds.max.y)
+            .collect::<Vec<T>>()
+            .into_iter()
+            .rev()
+            .map(|y| {
+                get_range(bounds.min.x, bounds.max.x)
+                    .map(|x| {
+                        let pos = Position { x, y };
+                        match knot_by_pos.get(&pos) {
+                            Some(knot_idx) => get_knot_label(*knot_idx, knots.len()),
+                            None => {
+                                get_background_cell(&pos, visited.is_some_and(|v| v.contains(&pos)))
+                            }
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+fn get_range<T: Coordinate>(min: T, max: T) -> impl Iterator<Item = T> {
+    std::iter::successors(Some(min), move |value| match *value < max {
+        true => value.checked_add(T::ONE),
+        false => None,
+    })
+}
+
+fn get_background_cell<T: Coordinate>(pos: &Position<T>, visited: bool) -> char {
+    let is_start = *pos == Position::origin();
+    match (is_start, visited) {
+        (true, _) => START_CELL,
+        (false, true) => VISITED_CELL,
+        (false, false) => EMPTY_CELL,
+    }
+}
+
+pub fn get_simulation_bounds(config: &RopeConfig, commands: &[Command]) -> Bounds {
+    let mut rope = Rope::new(config);
+    let mut bounds = rope.get_bounds();
+    commands.iter().for_each(|command| {
+        for _ in 0..command.nb_steps {
+            rope.process_move(&command.direction);
+            rope.get_knots()
+                .iter()
+                .for_each(|knot| bounds.include(knot));
+        }
+    });
+    bounds
+}
+
+pub fn render_frames(config: &RopeConfig, commands: &[Command], step: &FrameStep) -> Vec<Frame> {
+    let bounds = get_simulation_bounds(config, commands);
+    let mut rope = Rope::new(config);
+    let mut frames = vec![Frame {
+        label: String::from("Initial State"),
+        image: rope.render_within(&bounds),
+    }];
+
+    commands.iter().for_each(|command| match step {
+        FrameStep::Command => {
+            rope.process_command(command);
+            frames.push(Frame {
+                label: command.to_string(),
+                image: rope.render_within(&bounds),
+            });
+        }
+        FrameStep::Step => {
+            for step_idx in 1..=command.nb_steps {
+                rope.process_move(&command.direction);
+                frames.push(Frame {
+                    label: format!("{} ({}/{})", command, step_idx, command.nb_steps),
+                    image: rope.render_within(&bounds),
+                });
+            }
+        }
+    });
+    frames
+}
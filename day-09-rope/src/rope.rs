@@ -0,0 +1,153 @@
+use std::collections::BTreeMap;
+
+use crate::command::{Command, Direction};
+use crate::coordinate::{Coordinate, PosUnit, Position};
+use crate::visited::VisitedSet;
+
+pub const ROPE_SIZE: usize = 2;
+pub const LONG_ROPE_SIZE: usize = 10;
+
+pub struct RopeConfig {
+    pub length: usize,
+    pub diagonal_moves: bool,
+    pub tracked_knots: Vec<usize>,
+}
+
+impl Default for RopeConfig {
+    fn default() -> Self {
+        Self::new(ROPE_SIZE)
+    }
+}
+
+impl RopeConfig {
+    pub fn new(length: usize) -> Self {
+        RopeConfig {
+            length,
+            diagonal_moves: false,
+            tracked_knots: vec![length.saturating_sub(1)],
+        }
+    }
+}
+
+pub struct Rope<T: Coordinate = PosUnit> {
+    knots: Vec<Position<T>>,
+    diagonal_moves: bool,
+    visited: BTreeMap<usize, VisitedSet<T>>,
+}
+
+impl Default for Rope {
+    fn default() -> Self {
+        Self::new(&RopeConfig::default())
+    }
+}
+
+impl Rope {
+    pub fn new(config: &RopeConfig) -> Self {
+        Self::with_config(config)
+    }
+}
+
+impl<T: Coordinate> Rope<T> {
+    pub fn with_config(config: &RopeConfig) -> Self {
+        if config.length == 0 {
+            panic!("A rope needs at least one knot");
+        }
+        let initial_pos = Position::origin();
+        let knots = vec![initial_pos.clone(); config.length];
+
+        let visited = config
+            .tracked_knots
+            .iter()
+            .map(|knot_idx| {
+                if *knot_idx >= config.length {
+                    panic!(
+                        "Cannot track knot `{}` on a rope of length `{}`",
+                        knot_idx, config.length
+                    );
+                }
+                let mut history = VisitedSet::new();
+                history.insert(&initial_pos);
+                (*knot_idx, history)
+            })
+            .collect();
+
+        Rope {
+            knots,
+            diagonal_moves: config.diagonal_moves,
+            visited,
+        }
+    }
+
+    pub fn get_knots(&self) -> &[Position<T>] {
+        &self.knots
+    }
+
+    pub fn get_visited(&self, knot_idx: usize) -> &VisitedSet<T> {
+        self.visited
+            .get(&knot_idx)
+            .unwrap_or_else(|| panic!("Knot `{}` is not tracked", knot_idx))
+    }
+
+    pub fn get_last_tracked_visited(&self) -> Option<&VisitedSet<T>> {
+        self.visited.values().next_back()
+    }
+
+    pub fn into_visited(self) -> BTreeMap<usize, VisitedSet<T>> {
+        self.visited
+    }
+
+    pub fn process_command(&mut self, command: &Command) {
+        for _ in 0..command.nb_steps {
+            self.process_move(&command.direction);
+        }
+    }
+
+    pub fn process_move(&mut self, direction: &Direction) {
+        self.move_head(direction);
+        self.update_knots();
+        self.record_tracked_pos();
+    }
+
+    fn move_head(&mut self, direction: &Direction) {
+        if direction.is_diagonal() && !self.diagonal_moves {
+            panic!("Diagonal move `{:?}` is not allowed", direction);
+        }
+        let pos_head = self.knots.first_mut().unwrap();
+        pos_head.apply_diff(&direction.get_offset());
+    }
+
+    fn update_knots(&mut self) {
+        let mut previous_knot = self.knots.first().unwrap().clone();
+
+        self.knots.iter_mut().skip(1).for_each(|knot| {
+            let diff = previous_knot.sub(knot);
+            if diff.is_too_far() {
+                let catchup_move = diff.get_catchup_move();
+                knot.apply_diff(&catchup_move);
+            }
+            previous_knot = knot.clone();
+        });
+    }
+
+    fn record_tracked_pos(&mut self) {
+        let knots = &self.knots;
+        self.visited.iter_mut().for_each(|(knot_idx, history)| {
+            history.insert(&knots[*knot_idx]);
+        });
+    }
+}
+
+pub fn simulate(config: &RopeConfig, commands: &[Command]) -> BTreeMap<usize, VisitedSet> {
+    simulate_as(config, commands)
+}
+
+pub fn simulate_as<T: Coordinate>(
+    config: &RopeConfig,
+    commands: &[Command],
+) -> BTreeMap<usize, VisitedSet<T>> {
+    let mut rope = Rope::with_config(config);
+    commands
+        .iter()
+        .for_each(|command| rope.process_command(command));
+    rope.into_visited()
+}
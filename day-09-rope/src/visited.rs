@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use crate::coordinate::{Coordinate, PosUnit, Position};
+
+// Each chunk covers a square of CHUNK_SIZE cells, one u64 per row
+const CHUNK_SIZE: usize = u64::BITS as usize;
+
+pub struct VisitedSet<T: Coordinate = PosUnit> {
+    chunks: HashMap<(T, T), [u64; CHUNK_SIZE]>,
+    len: usize,
+}
+
+impl<T: Coordinate> Default for VisitedSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Coordinate> VisitedSet<T> {
+    pub fn new() -> Self {
+        VisitedSet {
+            chunks: HashMap::new(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get_nb_chunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    pub fn insert(&mut self, pos: &Position<T>) -> bool {
+        let (chunk_x, local_x) = pos.x.split_chunk(CHUNK_SIZE);
+        let (chunk_y, local_y) = pos.y.split_chunk(CHUNK_SIZE);
+        let row = &mut self
+            .chunks
+            .entry((chunk_x, chunk_y))
+            .or_insert([0; CHUNK_SIZE])[local_y];
+        let mask = 1 << local_x;
+        match *row & mask {
+            0 => {
+                *row |= mask;
+                self.len += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn contains(&self, pos: &Position<T>) -> bool {
+        let (chunk_x, local_x) = pos.x.split_chunk(CHUNK_SIZE);
+        let (chunk_y, local_y) = pos.y.split_chunk(CHUNK_SIZE);
+        self.chunks
+            .get(&(chunk_x, chunk_y))
+            .is_some_and(|chunk| chunk[local_y] & (1 << local_x) != 0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Position<T>> + '_ {
+        self.chunks.iter().flat_map(|((chunk_x, chunk_y), chunk)| {
+            chunk.iter().enumerate().flat_map(move |(local_y, row)| {
+                (0..CHUNK_SIZE)
+                    .filter(move |local_x| row & (1 << local_x) != 0)
+                    .map(move |local_x| Position {
+                        x: T::join_chunk(*chunk_x, local_x, CHUNK_SIZE),
+                        y: T::join_chunk(*chunk_y, local_y, CHUNK_SIZE),
+                    })
+            })
+        })
+    }
+}
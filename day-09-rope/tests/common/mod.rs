@@ -0,0 +1,18 @@
+// Shared by several test crates, each of them only uses part of it
+#![allow(dead_code)]
+
+use day_09_rope::Command;
+
+pub fn build_commands() -> Vec<Command> {
+    ["R 4", "U 4", "L 3", "D 1", "R 4", "D 1", "L 5", "R 2"]
+        .iter()
+        .map(|line| Command::parse(line))
+        .collect()
+}
+
+pub fn build_larger_commands() -> Vec<Command> {
+    ["R 5", "U 8", "L 8", "D 3", "R 17", "D 10", "L 25", "U 20"]
+        .iter()
+        .map(|line| Command::parse(line))
+        .collect()
+}
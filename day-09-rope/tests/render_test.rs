@@ -0,0 +1,90 @@
+mod common;
+
+mod render {
+    use crate::common::build_commands;
+    use day_09_rope::{simulate, Rope, RopeConfig};
+
+    #[test]
+    fn test_initial_state() {
+        // Given
+        let rope = Rope::new(&RopeConfig::new(10));
+
+        // When
+        let result = rope.render();
+
+        // Then
+        assert_eq!(result, "H");
+    }
+
+    #[test]
+    fn test_after_commands() {
+        // Given
+        let config = RopeConfig::default();
+        let mut rope = Rope::new(&config);
+        build_commands()
+            .iter()
+            .for_each(|command| rope.process_command(command));
+
+        // When
+        let result = rope.render();
+
+        // Then
+        assert_eq!(simulate(&config, &build_commands())[&1].len(), 13);
+        assert_eq!(result, "..##.\n...##\n.TH##\n....#\ns###.");
+    }
+
+    #[test]
+    fn test_knot_labels() {
+        // Given
+        let mut rope = Rope::new(&RopeConfig::new(10));
+        build_commands()
+            .iter()
+            .take(2)
+            .for_each(|command| rope.process_command(command));
+
+        // When
+        let result = rope.render();
+
+        // Then
+        assert_eq!(result, "....H\n....1\n..432\n.5...\n6....");
+    }
+}
+
+mod render_frames {
+    use crate::common::build_commands;
+    use day_09_rope::{render_frames, FrameStep, RopeConfig};
+
+    #[test]
+    fn test_frame_per_command() {
+        // Given
+        let config = RopeConfig::default();
+
+        // When
+        let result = render_frames(&config, &build_commands(), &FrameStep::Command);
+
+        // Then
+        assert_eq!(result.len(), 9);
+        assert_eq!(result[0].label, "Initial State");
+        assert_eq!(result[0].image, "......\n......\n......\n......\nH.....");
+        assert_eq!(result[1].label, "R 4");
+        assert_eq!(result[1].image, "......\n......\n......\n......\ns##TH.");
+    }
+
+    #[test]
+    fn test_frame_per_step() {
+        // Given
+        let config = RopeConfig::default();
+
+        // When
+        let result = render_frames(&config, &build_commands(), &FrameStep::Step);
+
+        // Then
+        assert_eq!(result.len(), 25);
+        assert_eq!(result[1].label, "R 4 (1/4)");
+        assert_eq!(result[1].image, "......\n......\n......\n......\nTH....");
+        assert_eq!(
+            result[1].to_string(),
+            "== R 4 (1/4) ==\n\n......\n......\n......\n......\nTH...."
+        );
+    }
+}
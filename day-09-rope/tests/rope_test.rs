@@ -0,0 +1,131 @@
+mod common;
+
+mod parse {
+    use day_09_rope::{Command, Direction};
+
+    #[test]
+    fn test_orthogonal() {
+        // Given
+        let text = "R 4";
+
+        // When
+        let result = Command::parse(text);
+
+        // Then
+        assert_eq!(result.direction, Direction::Right);
+        assert_eq!(result.nb_steps, 4);
+    }
+
+    #[test]
+    fn test_diagonal() {
+        // Given
+        let text = "UL 12";
+
+        // When
+        let result = Command::parse(text);
+
+        // Then
+        assert_eq!(result.direction, Direction::UpLeft);
+        assert_eq!(result.nb_steps, 12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_direction() {
+        // When
+        Command::parse("X 1");
+    }
+}
+
+mod simulate {
+    use crate::common::{build_commands, build_larger_commands};
+    use day_09_rope::{simulate, RopeConfig};
+
+    #[test]
+    fn test_default_rope() {
+        // Given
+        let config = RopeConfig::default();
+
+        // When
+        let result = simulate(&config, &build_commands());
+
+        // Then
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[&1].len(), 13);
+    }
+
+    #[test]
+    fn test_long_rope() {
+        // Given
+        let config = RopeConfig::new(10);
+
+        // When
+        let result = simulate(&config, &build_larger_commands());
+
+        // Then
+        assert_eq!(result[&9].len(), 36);
+    }
+
+    #[test]
+    fn test_several_tracked_knots() {
+        // Given
+        let mut config = RopeConfig::new(10);
+        config.tracked_knots = vec![0, 1, 9];
+
+        // When
+        let result = simulate(&config, &build_commands());
+
+        // Then
+        assert_eq!(result.keys().copied().collect::<Vec<_>>(), vec![0, 1, 9]);
+        assert_eq!(result[&1].len(), 13);
+        assert_eq!(result[&9].len(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_untracked_knot_out_of_rope() {
+        // Given
+        let mut config = RopeConfig::new(2);
+        config.tracked_knots = vec![2];
+
+        // When
+        simulate(&config, &build_commands());
+    }
+}
+
+mod process_move {
+    use day_09_rope::{Direction, Position, Rope, RopeConfig};
+
+    #[test]
+    fn test_diagonal_head_move() {
+        // Given
+        let mut config = RopeConfig::new(3);
+        config.diagonal_moves = true;
+        let mut rope = Rope::new(&config);
+
+        // When
+        rope.process_move(&Direction::UpRight);
+        rope.process_move(&Direction::UpRight);
+
+        // Then
+        assert_eq!(
+            rope.get_knots(),
+            &[
+                Position { x: 2, y: 2 },
+                Position { x: 1, y: 1 },
+                Position { x: 0, y: 0 },
+            ]
+        );
+        assert_eq!(rope.get_visited(2).len(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_diagonal_head_move_disabled() {
+        // Given
+        let mut rope = Rope::default();
+
+        // When
+        rope.process_move(&Direction::DownLeft);
+    }
+}
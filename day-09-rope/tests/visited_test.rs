@@ -0,0 +1,117 @@
+use day_09_rope::Position;
+
+mod insert {
+    use crate::position;
+    use day_09_rope::VisitedSet;
+
+    #[test]
+    fn test_across_chunks() {
+        // Given
+        let mut visited: VisitedSet = VisitedSet::new();
+
+        // When
+        let inserted = [(0, 0), (-1, 0), (63, 64), (-65, -1), (0, 0)]
+            .iter()
+            .map(|(x, y)| visited.insert(&position(*x, *y)))
+            .collect::<Vec<bool>>();
+
+        // Then
+        assert_eq!(inserted, vec![true, true, true, true, false]);
+        assert_eq!(visited.len(), 4);
+        assert_eq!(visited.get_nb_chunks(), 4);
+    }
+
+    #[test]
+    fn test_dense_area_stays_in_one_chunk() {
+        // Given
+        let mut visited: VisitedSet = VisitedSet::new();
+
+        // When
+        (0..64).for_each(|x| {
+            (0..64).for_each(|y| {
+                visited.insert(&position(x, y));
+            })
+        });
+
+        // Then
+        assert_eq!(visited.len(), 4096);
+        assert_eq!(visited.get_nb_chunks(), 1);
+    }
+}
+
+mod contains {
+    use crate::position;
+    use day_09_rope::VisitedSet;
+
+    #[test]
+    fn test_negative_coordinates() {
+        // Given
+        let mut visited: VisitedSet = VisitedSet::new();
+        visited.insert(&position(-64, -1));
+
+        // When
+        let result = (
+            visited.contains(&position(-64, -1)),
+            visited.contains(&position(0, -1)),
+            visited.contains(&position(-64, 63)),
+        );
+
+        // Then
+        assert_eq!(result, (true, false, false));
+    }
+}
+
+mod iter {
+    use crate::position;
+    use day_09_rope::VisitedSet;
+
+    #[test]
+    fn test_round_trip() {
+        // Given
+        let mut visited: VisitedSet = VisitedSet::new();
+        let positions = [(-130, 7), (-1, -1), (0, 0), (200, -70)];
+        positions.iter().for_each(|(x, y)| {
+            visited.insert(&position(*x, *y));
+        });
+
+        // When
+        let mut result = visited
+            .iter()
+            .map(|pos| (pos.x, pos.y))
+            .collect::<Vec<(i32, i32)>>();
+        result.sort();
+
+        // Then
+        assert_eq!(result, positions.to_vec());
+    }
+}
+
+mod simulate_as {
+    use day_09_rope::{simulate_as, Command, RopeConfig};
+
+    #[test]
+    fn test_beyond_i16() {
+        // Given
+        let commands = vec![Command::parse("R 40000")];
+
+        // When
+        let result = simulate_as::<i64>(&RopeConfig::default(), &commands);
+
+        // Then
+        assert_eq!(result[&1].len(), 40000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Coordinate overflow")]
+    fn test_overflow_is_detected() {
+        // Given
+        let commands = vec![Command::parse("L 40000")];
+
+        // When
+        simulate_as::<i16>(&RopeConfig::default(), &commands);
+    }
+}
+
+fn position(x: i32, y: i32) -> Position {
+    Position { x, y }
+}
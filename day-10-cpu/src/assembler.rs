@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use crate::instruction::{Instruction, InstructionSet};
+
+const COMMENT_CHAR: char = '#';
+const LABEL_SUFFIX: char = ':';
+
+pub struct Assembler {
+    pub instruction_set: InstructionSet,
+}
+
+impl Default for Assembler {
+    fn default() -> Self {
+        Self::new(InstructionSet::default())
+    }
+}
+
+impl Assembler {
+    pub fn new(instruction_set: InstructionSet) -> Self {
+        Assembler { instruction_set }
+    }
+
+    pub fn assemble<'a>(&self, lines: impl IntoIterator<Item = &'a str>) -> Vec<Instruction> {
+        let lines: Vec<(Option<&str>, &str)> = lines
+            .into_iter()
+            .map(|line| line.split(COMMENT_CHAR).next().unwrap().trim())
+            .filter(|line| !line.is_empty())
+            .map(split_label)
+            .collect();
+
+        let labels = get_labels(&lines);
+        lines
+            .iter()
+            .filter(|(_, instruction)| !instruction.is_empty())
+            .map(|(_, instruction)| self.instruction_set.parse(instruction, &labels))
+            .collect()
+    }
+}
+
+// A label is either alone on its line or in front of an instruction, as in
+// `loop: addx 1`
+fn split_label(line: &str) -> (Option<&str>, &str) {
+    let (first_word, rest) = match line.split_once(char::is_whitespace) {
+        Some((first_word, rest)) => (first_word, rest.trim_start()),
+        None => (line, ""),
+    };
+    match first_word.strip_suffix(LABEL_SUFFIX) {
+        Some(label) => (Some(label), rest),
+        None => (None, line),
+    }
+}
+
+// A label points at the next instruction, so a program can jump to its end
+fn get_labels(lines: &[(Option<&str>, &str)]) -> HashMap<String, usize> {
+    let mut labels = HashMap::new();
+    let mut instruction_idx = 0;
+    lines.iter().for_each(|(label, instruction)| {
+        if let Some(label) = label {
+            if labels.insert(label.to_string(), instruction_idx).is_some() {
+                panic!("Duplicate label: `{}`", label);
+            }
+        }
+        if !instruction.is_empty() {
+            instruction_idx += 1;
+        }
+    });
+    labels
+}
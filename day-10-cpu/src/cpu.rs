@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+
+use crate::instruction::{Instruction, Operand, Payload, Register, RegisterUnit};
+
+pub const STARTING_VALUE: RegisterUnit = 1;
+pub const REGISTER_X: Register = 'x';
+
+// Value of the X register while the cycle is running, before the current
+// instruction completes
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CycleEvent {
+    pub cycle: usize,
+    pub x: RegisterUnit,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Control {
+    Continue,
+    Break,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RunOutcome {
+    Finished,
+    Break(usize),
+}
+
+pub trait Observer {
+    fn on_cycle(&mut self, event: &CycleEvent) -> Control;
+}
+
+pub struct Cpu {
+    program: Vec<Instruction>,
+    instruction_idx: usize,
+    registers: BTreeMap<Register, RegisterUnit>,
+    cycle_number: usize,
+    remaining_cycles: usize,
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cpu {
+    pub fn new() -> Self {
+        Self::with_program(Vec::new())
+    }
+
+    pub fn with_program(program: Vec<Instruction>) -> Self {
+        Cpu {
+            program,
+            instruction_idx: 0,
+            registers: BTreeMap::from([(REGISTER_X, STARTING_VALUE)]),
+            cycle_number: 0,
+            remaining_cycles: 0,
+        }
+    }
+
+    pub fn add_instruction(&mut self, instruction: Instruction) {
+        if self.cycle_number > 0 {
+            panic!("Cannot load more instructions when processor is executing");
+        }
+        self.program.push(instruction);
+    }
+
+    pub fn get_cycle_number(&self) -> usize {
+        self.cycle_number
+    }
+
+    pub fn get_register(&self, register: Register) -> RegisterUnit {
+        self.registers.get(&register).copied().unwrap_or(0)
+    }
+
+    pub fn get_register_x(&self) -> RegisterUnit {
+        self.get_register(REGISTER_X)
+    }
+
+    pub fn get_registers(&self) -> &BTreeMap<Register, RegisterUnit> {
+        &self.registers
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.instruction_idx >= self.program.len()
+    }
+
+    pub fn run(&mut self, observer: &mut dyn Observer) -> RunOutcome {
+        while !self.is_finished() {
+            if self.do_cycle(observer) == Control::Break {
+                return RunOutcome::Break(self.cycle_number);
+            }
+        }
+        RunOutcome::Finished
+    }
+
+    pub fn do_cycle(&mut self, observer: &mut dyn Observer) -> Control {
+        if self.is_finished() {
+            return Control::Continue;
+        }
+        self.start_cycle();
+        let control = observer.on_cycle(&CycleEvent {
+            cycle: self.cycle_number,
+            x: self.get_register_x(),
+        });
+        self.end_cycle();
+        control
+    }
+
+    fn start_cycle(&mut self) {
+        if self.remaining_cycles == 0 {
+            self.remaining_cycles = self.program[self.instruction_idx].nb_cycles;
+        }
+        self.cycle_number += 1;
+        self.remaining_cycles -= 1;
+    }
+
+    fn end_cycle(&mut self) {
+        if self.remaining_cycles == 0 {
+            self.execute_instruction();
+        }
+    }
+
+    fn execute_instruction(&mut self) {
+        let next_idx = self.instruction_idx + 1;
+        self.instruction_idx = match self.program[self.instruction_idx].payload {
+            Payload::Noop => next_idx,
+            Payload::Add(register, operand) => {
+                let value = self.get_operand_value(operand);
+                *self.registers.entry(register).or_insert(0) += value;
+                next_idx
+            }
+            Payload::Mul(register, operand) => {
+                let value = self.get_operand_value(operand);
+                *self.registers.entry(register).or_insert(0) *= value;
+                next_idx
+            }
+            Payload::Jump(target) => target,
+            Payload::JumpIfZero(register, target) => match self.get_register(register) == 0 {
+                true => target,
+                false => next_idx,
+            },
+            Payload::JumpIfNotZero(register, target) => match self.get_register(register) != 0 {
+                true => target,
+                false => next_idx,
+            },
+        };
+    }
+
+    fn get_operand_value(&self, operand: Operand) -> RegisterUnit {
+        match operand {
+            Operand::Value(value) => value,
+            Operand::Register(register) => self.get_register(register),
+        }
+    }
+}
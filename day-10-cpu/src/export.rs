@@ -0,0 +1,44 @@
+use crate::screen::{Screen, PIXEL_ON};
+
+const MAX_GRAY_VALUE: u8 = 255;
+
+impl Screen {
+    // Lit pixels are black, as in the puzzle's rendering of the letters
+    pub fn to_pbm(&self) -> String {
+        self.to_netpbm("P1", None, "1", "0")
+    }
+
+    // Lit pixels are white, as on the actual CRT
+    pub fn to_pgm(&self) -> String {
+        let on = MAX_GRAY_VALUE.to_string();
+        self.to_netpbm("P2", Some(MAX_GRAY_VALUE), &on, "0")
+    }
+
+    fn to_netpbm(&self, magic: &str, max_value: Option<u8>, on: &str, off: &str) -> String {
+        let pixels: Vec<String> = self
+            .rows
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|pixel| match *pixel == PIXEL_ON {
+                        true => on,
+                        false => off,
+                    })
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            })
+            .collect();
+        let max_value_line = match max_value {
+            Some(max_value) => format!("{}\n", max_value),
+            None => String::new(),
+        };
+        format!(
+            "{}\n{} {}\n{}{}\n",
+            magic,
+            self.rows.get_width(),
+            self.rows.get_height(),
+            max_value_line,
+            pixels.join("\n")
+        )
+    }
+}
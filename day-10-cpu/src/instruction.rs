@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+pub type RegisterUnit = isize;
+pub type Register = char;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Opcode {
+    Noop,
+    Add,
+    Mul,
+    Jump,
+    JumpIfZero,
+    JumpIfNotZero,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 6] = [
+        Opcode::Noop,
+        Opcode::Add,
+        Opcode::Mul,
+        Opcode::Jump,
+        Opcode::JumpIfZero,
+        Opcode::JumpIfNotZero,
+    ];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Opcode::Noop => "noop",
+            Opcode::Add => "add",
+            Opcode::Mul => "mul",
+            Opcode::Jump => "jmp",
+            Opcode::JumpIfZero => "jz",
+            Opcode::JumpIfNotZero => "jnz",
+        }
+    }
+
+    fn get_default_nb_cycles(&self) -> usize {
+        match self {
+            Opcode::Noop => 1,
+            Opcode::Add => 2,
+            Opcode::Mul => 4,
+            Opcode::Jump => 1,
+            Opcode::JumpIfZero => 2,
+            Opcode::JumpIfNotZero => 2,
+        }
+    }
+
+    // Register-specific mnemonics are the opcode name followed by the register,
+    // such as `addx` or `jnzy`
+    fn split_mnemonic(word: &str) -> (Self, Option<Register>) {
+        if word == Opcode::Jump.get_name() {
+            return (Opcode::Jump, None);
+        }
+        if word == Opcode::Noop.get_name() {
+            return (Opcode::Noop, None);
+        }
+        let mut chars = word.chars();
+        let register = chars
+            .next_back()
+            .filter(|register| register.is_ascii_lowercase())
+            .unwrap_or_else(|| panic!("Invalid instruction word: `{}`", word));
+        let name = chars.as_str();
+        let opcode = Opcode::ALL
+            .iter()
+            .find(|opcode| opcode.get_name() == name)
+            .unwrap_or_else(|| panic!("Invalid instruction word: `{}`", word));
+        (*opcode, Some(register))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Operand {
+    Value(RegisterUnit),
+    Register(Register),
+}
+
+impl Operand {
+    pub fn parse(word: &str) -> Self {
+        match word.parse() {
+            Ok(value) => Operand::Value(value),
+            Err(_) => {
+                let mut chars = word.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(register), None) if register.is_ascii_lowercase() => {
+                        Operand::Register(register)
+                    }
+                    _ => panic!("Invalid operand: `{}`", word),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Payload {
+    Noop,
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Jump(usize),
+    JumpIfZero(Register, usize),
+    JumpIfNotZero(Register, usize),
+}
+
+impl Payload {
+    pub fn get_opcode(&self) -> Opcode {
+        match self {
+            Payload::Noop => Opcode::Noop,
+            Payload::Add(_, _) => Opcode::Add,
+            Payload::Mul(_, _) => Opcode::Mul,
+            Payload::Jump(_) => Opcode::Jump,
+            Payload::JumpIfZero(_, _) => Opcode::JumpIfZero,
+            Payload::JumpIfNotZero(_, _) => Opcode::JumpIfNotZero,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Instruction {
+    pub nb_cycles: usize,
+    pub payload: Payload,
+}
+
+impl Instruction {
+    pub fn parse(s: &str) -> Self {
+        InstructionSet::default().parse(s, &HashMap::new())
+    }
+}
+
+pub struct InstructionSet {
+    nb_cycles: HashMap<Opcode, usize>,
+}
+
+impl Default for InstructionSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InstructionSet {
+    pub fn new() -> Self {
+        let nb_cycles = Opcode::ALL
+            .iter()
+            .map(|opcode| (*opcode, opcode.get_default_nb_cycles()))
+            .collect();
+        InstructionSet { nb_cycles }
+    }
+
+    pub fn get_nb_cycles(&self, opcode: Opcode) -> usize {
+        self.nb_cycles[&opcode]
+    }
+
+    pub fn set_nb_cycles(&mut self, opcode: Opcode, nb_cycles: usize) {
+        if nb_cycles == 0 {
+            panic!(
+                "Instruction `{}` needs at least one cycle",
+                opcode.get_name()
+            );
+        }
+        self.nb_cycles.insert(opcode, nb_cycles);
+    }
+
+    pub fn parse(&self, s: &str, labels: &HashMap<String, usize>) -> Instruction {
+        let mut words = s.split_whitespace();
+        let instruction_word = words.next().unwrap();
+        let (opcode, register) = Opcode::split_mnemonic(instruction_word);
+        let mut next_word = || {
+            words
+                .next()
+                .unwrap_or_else(|| panic!("Missing operand in instruction: `{}`", s))
+        };
+
+        let payload = match (opcode, register) {
+            (Opcode::Noop, None) => Payload::Noop,
+            (Opcode::Jump, None) => Payload::Jump(parse_target(next_word(), labels)),
+            (Opcode::Add, Some(register)) => Payload::Add(register, Operand::parse(next_word())),
+            (Opcode::Mul, Some(register)) => Payload::Mul(register, Operand::parse(next_word())),
+            (Opcode::JumpIfZero, Some(register)) => {
+                Payload::JumpIfZero(register, parse_target(next_word(), labels))
+            }
+            (Opcode::JumpIfNotZero, Some(register)) => {
+                Payload::JumpIfNotZero(register, parse_target(next_word(), labels))
+            }
+            _ => panic!("Invalid instruction: `{}`", s),
+        };
+        Instruction {
+            nb_cycles: self.get_nb_cycles(opcode),
+            payload,
+        }
+    }
+}
+
+fn parse_target(word: &str, labels: &HashMap<String, usize>) -> usize {
+    match labels.get(word) {
+        Some(target) => *target,
+        None => word
+            .parse()
+            .unwrap_or_else(|_| panic!("Unknown label: `{}`", word)),
+    }
+}
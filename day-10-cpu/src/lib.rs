@@ -0,0 +1,18 @@
+mod assembler;
+mod cpu;
+mod export;
+mod instruction;
+mod ocr;
+mod probes;
+mod screen;
+
+pub use assembler::Assembler;
+pub use cpu::{Control, Cpu, CycleEvent, Observer, RunOutcome, REGISTER_X, STARTING_VALUE};
+pub use instruction::{
+    Instruction, InstructionSet, Opcode, Operand, Payload, Register, RegisterUnit,
+};
+pub use ocr::{read_letters, OcrError, GLYPH_HEIGHT, GLYPH_WIDTH};
+pub use probes::{CycleBreakpoint, Observers, SignalStrength, Trace, XChangeBreakpoint};
+pub use screen::{
+    Overflow, Screen, ScreenConfig, PIXEL_OFF, PIXEL_ON, SCREEN_HEIGHT, SCREEN_WIDTH, SPRITE_WIDTH,
+};
@@ -0,0 +1,110 @@
+use std::fmt;
+
+use grid::{Grid, Pos};
+
+use crate::screen::{Screen, PIXEL_ON};
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+const GLYPH_SPACING: usize = 1;
+const UNKNOWN_LETTER: char = '?';
+
+const GLYPHS: [(char, [&str; GLYPH_HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+    (' ', ["....", "....", "....", "....", "....", "...."]),
+];
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct OcrError {
+    // Text read so far, with `?` in place of each unrecognized glyph
+    pub text: String,
+    // Top-left pixel of each unrecognized glyph
+    pub positions: Vec<Pos>,
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let positions = self
+            .positions
+            .iter()
+            .map(|pos| format!("({}, {})", pos.x, pos.y))
+            .collect::<Vec<String>>()
+            .join(", ");
+        write!(
+            f,
+            "Could not read `{}`, unrecognized glyphs at {}",
+            self.text, positions
+        )
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+impl Screen {
+    pub fn read_letters(&self) -> Result<String, OcrError> {
+        read_letters(&self.rows, PIXEL_ON)
+    }
+}
+
+// Every band of GLYPH_HEIGHT rows is read as one line of text
+pub fn read_letters(rows: &Grid<char>, pixel_on: char) -> Result<String, OcrError> {
+    let mut positions = Vec::new();
+    // The last glyph of a row is not followed by any spacing, a screen
+    // narrower than a glyph has no glyph at all
+    let nb_glyphs = (rows.get_width() + GLYPH_SPACING) / (GLYPH_WIDTH + GLYPH_SPACING);
+    let lines: Vec<String> = (0..rows.get_height() / GLYPH_HEIGHT)
+        .map(|band_idx| {
+            let glyph_y = band_idx * GLYPH_HEIGHT;
+            let line: String = (0..nb_glyphs)
+                .map(|glyph_idx| glyph_idx * (GLYPH_WIDTH + GLYPH_SPACING))
+                .map(|glyph_x| {
+                    let glyph_pos = Pos::new(glyph_x, glyph_y);
+                    match recognize_glyph(rows, &glyph_pos, pixel_on) {
+                        Some(letter) => letter,
+                        None => {
+                            positions.push(glyph_pos);
+                            UNKNOWN_LETTER
+                        }
+                    }
+                })
+                .collect();
+            line.trim_end().to_string()
+        })
+        .collect();
+
+    let text = lines.join("\n");
+    match positions.is_empty() {
+        true => Ok(text),
+        false => Err(OcrError { text, positions }),
+    }
+}
+
+fn recognize_glyph(rows: &Grid<char>, glyph_pos: &Pos, pixel_on: char) -> Option<char> {
+    GLYPHS
+        .iter()
+        .find(|(_, glyph)| {
+            glyph.iter().enumerate().all(|(y, glyph_row)| {
+                glyph_row.chars().enumerate().all(|(x, glyph_pixel)| {
+                    let pos = Pos::new(glyph_pos.x + x, glyph_pos.y + y);
+                    (rows[&pos] == pixel_on) == (glyph_pixel == '#')
+                })
+            })
+        })
+        .map(|(letter, _)| *letter)
+}
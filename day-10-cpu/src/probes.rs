@@ -0,0 +1,143 @@
+use crate::cpu::{Control, CycleEvent, Observer};
+use crate::instruction::RegisterUnit;
+
+const FIRST_INTERESTING_CYCLE: usize = 20;
+const INTERESTING_CYCLE_DISTANCE: usize = 40;
+
+pub struct SignalStrength {
+    pub first_cycle: usize,
+    pub interval: usize,
+    pub signals: Vec<RegisterUnit>,
+}
+
+impl Default for SignalStrength {
+    fn default() -> Self {
+        Self::new(FIRST_INTERESTING_CYCLE, INTERESTING_CYCLE_DISTANCE)
+    }
+}
+
+impl SignalStrength {
+    pub fn new(first_cycle: usize, interval: usize) -> Self {
+        SignalStrength {
+            first_cycle,
+            interval,
+            signals: Vec::new(),
+        }
+    }
+
+    pub fn get_sum(&self) -> RegisterUnit {
+        self.signals.iter().sum()
+    }
+
+    fn is_cycle_interesting(&self, cycle: usize) -> bool {
+        cycle >= self.first_cycle && (cycle - self.first_cycle).is_multiple_of(self.interval)
+    }
+}
+
+impl Observer for SignalStrength {
+    fn on_cycle(&mut self, event: &CycleEvent) -> Control {
+        if self.is_cycle_interesting(event.cycle) {
+            self.signals.push(event.x * event.cycle as RegisterUnit);
+        }
+        Control::Continue
+    }
+}
+
+#[derive(Default)]
+pub struct Trace {
+    pub events: Vec<CycleEvent>,
+}
+
+impl Trace {
+    pub fn new() -> Self {
+        Trace { events: Vec::new() }
+    }
+
+    pub fn render(&self) -> String {
+        self.events
+            .iter()
+            .map(|event| format!("cycle {:>4}: X = {}", event.cycle, event.x))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl Observer for Trace {
+    fn on_cycle(&mut self, event: &CycleEvent) -> Control {
+        self.events.push(*event);
+        Control::Continue
+    }
+}
+
+pub struct CycleBreakpoint {
+    pub cycle: usize,
+}
+
+impl CycleBreakpoint {
+    pub fn new(cycle: usize) -> Self {
+        CycleBreakpoint { cycle }
+    }
+}
+
+impl Observer for CycleBreakpoint {
+    fn on_cycle(&mut self, event: &CycleEvent) -> Control {
+        match event.cycle == self.cycle {
+            true => Control::Break,
+            false => Control::Continue,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct XChangeBreakpoint {
+    last_x: Option<RegisterUnit>,
+}
+
+impl XChangeBreakpoint {
+    pub fn new() -> Self {
+        XChangeBreakpoint { last_x: None }
+    }
+}
+
+impl Observer for XChangeBreakpoint {
+    fn on_cycle(&mut self, event: &CycleEvent) -> Control {
+        let last_x = self.last_x.replace(event.x);
+        match last_x.is_some_and(|x| x != event.x) {
+            true => Control::Break,
+            false => Control::Continue,
+        }
+    }
+}
+
+// Forwards every event to all observers, breaking when any of them does
+#[derive(Default)]
+pub struct Observers<'a> {
+    observers: Vec<&'a mut dyn Observer>,
+}
+
+impl<'a> Observers<'a> {
+    pub fn new() -> Self {
+        Observers {
+            observers: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, observer: &'a mut dyn Observer) {
+        self.observers.push(observer);
+    }
+}
+
+impl Observer for Observers<'_> {
+    fn on_cycle(&mut self, event: &CycleEvent) -> Control {
+        self.observers
+            .iter_mut()
+            .map(|observer| observer.on_cycle(event))
+            .fold(
+                Control::Continue,
+                |control, observer_control| match observer_control {
+                    Control::Break => Control::Break,
+                    Control::Continue => control,
+                },
+            )
+    }
+}
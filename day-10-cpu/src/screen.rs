@@ -0,0 +1,132 @@
+use grid::{Grid, Pos};
+
+use crate::cpu::{Control, CycleEvent, Observer};
+use crate::instruction::RegisterUnit;
+
+pub const SCREEN_WIDTH: usize = 40;
+pub const SCREEN_HEIGHT: usize = 6;
+pub const SPRITE_WIDTH: usize = 3;
+pub const PIXEL_OFF: char = ' ';
+pub const PIXEL_ON: char = 'X';
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Overflow {
+    // Go back to the top row and draw over the previous frame
+    Wrap,
+    // Add rows at the bottom as long as the program runs
+    Grow,
+}
+
+impl Overflow {
+    pub fn parse(word: &str) -> Self {
+        match word {
+            "wrap" => Overflow::Wrap,
+            "grow" => Overflow::Grow,
+            _ => panic!("Invalid overflow: `{}`", word),
+        }
+    }
+}
+
+pub struct ScreenConfig {
+    pub width: usize,
+    pub height: usize,
+    pub sprite_width: usize,
+    pub overflow: Overflow,
+}
+
+impl Default for ScreenConfig {
+    fn default() -> Self {
+        ScreenConfig {
+            width: SCREEN_WIDTH,
+            height: SCREEN_HEIGHT,
+            sprite_width: SPRITE_WIDTH,
+            overflow: Overflow::Wrap,
+        }
+    }
+}
+
+pub struct Screen {
+    pub rows: Grid<char>,
+    sprite_width: usize,
+    overflow: Overflow,
+    rtc_x: usize,
+    rtc_y: usize,
+}
+
+impl Default for Screen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Screen {
+    pub fn new() -> Self {
+        Self::with_config(&ScreenConfig::default())
+    }
+
+    pub fn with_config(config: &ScreenConfig) -> Self {
+        if config.width == 0 || config.height == 0 {
+            panic!("Invalid screen size: `{}x{}`", config.width, config.height);
+        }
+        let rows = Grid::new(config.width, config.height, PIXEL_OFF);
+
+        Screen {
+            rows,
+            sprite_width: config.sprite_width,
+            overflow: config.overflow,
+            rtc_x: 0,
+            rtc_y: 0,
+        }
+    }
+
+    pub fn get_rtc_x(&self) -> usize {
+        self.rtc_x
+    }
+
+    pub fn get_rtc_y(&self) -> usize {
+        self.rtc_y
+    }
+
+    pub fn draw_pixel(&mut self, sprite_middle_x: RegisterUnit) {
+        let pixel = match self.is_pixel_in_sprite(sprite_middle_x) {
+            true => PIXEL_ON,
+            false => PIXEL_OFF,
+        };
+        // Grown rows are only added once there is a pixel to draw on them
+        if self.rtc_y == self.rows.get_height() {
+            self.rows.push_row(vec![PIXEL_OFF; self.rows.get_width()]);
+        }
+        self.rows.set(&Pos::new(self.rtc_x, self.rtc_y), pixel);
+        self.move_rtc();
+    }
+
+    pub fn move_rtc(&mut self) {
+        self.rtc_x += 1;
+        if self.rtc_x < self.rows.get_width() {
+            return;
+        }
+        self.rtc_x = 0;
+        self.rtc_y += 1;
+        if self.rtc_y == self.rows.get_height() && self.overflow == Overflow::Wrap {
+            self.rtc_y = 0;
+        }
+    }
+
+    pub fn is_pixel_in_sprite(&self, sprite_middle_x: RegisterUnit) -> bool {
+        let sprite_width = self.sprite_width as RegisterUnit;
+        let sprite_start = sprite_middle_x - sprite_width / 2;
+        let rtc_x = self.rtc_x as RegisterUnit;
+        rtc_x >= sprite_start && rtc_x < sprite_start + sprite_width
+    }
+
+    pub fn print(&self) {
+        println!("{}", self.rows);
+    }
+}
+
+impl Observer for Screen {
+    fn on_cycle(&mut self, event: &CycleEvent) -> Control {
+        self.draw_pixel(event.x);
+        Control::Continue
+    }
+}
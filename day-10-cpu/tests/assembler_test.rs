@@ -0,0 +1,162 @@
+use day_10_cpu::{Assembler, Cpu, Trace};
+
+mod parse {
+    use day_10_cpu::{Instruction, Operand, Payload};
+
+    #[test]
+    fn test_addx() {
+        // Given
+        let line = "addx -15";
+
+        // When
+        let result = Instruction::parse(line);
+
+        // Then
+        assert_eq!(
+            result,
+            Instruction {
+                nb_cycles: 2,
+                payload: Payload::Add('x', Operand::Value(-15)),
+            }
+        );
+    }
+
+    #[test]
+    fn test_register_operand() {
+        // Given
+        let line = "muly x";
+
+        // When
+        let result = Instruction::parse(line);
+
+        // Then
+        assert_eq!(result.payload, Payload::Mul('y', Operand::Register('x')));
+        assert_eq!(result.nb_cycles, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_instruction() {
+        // When
+        Instruction::parse("subx 3");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid instruction: `jmpx 3`")]
+    fn test_jump_with_register() {
+        // When
+        Instruction::parse("jmpx 3");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid instruction: `noopq`")]
+    fn test_noop_with_register() {
+        // When
+        Instruction::parse("noopq");
+    }
+}
+
+mod assemble {
+    use crate::run_program;
+    use day_10_cpu::{Assembler, InstructionSet, Opcode, Payload};
+
+    #[test]
+    fn test_labels_and_comments() {
+        // Given
+        let lines = [
+            "# Computes 2 ^ 3 in register x",
+            "addy 3",
+            "",
+            "loop:",
+            "    mulx 2",
+            "    addy -1 # decrement the counter",
+            "    jnzy loop",
+            "end:",
+        ];
+
+        // When
+        let result = Assembler::default().assemble(lines);
+
+        // Then
+        assert_eq!(result.len(), 4);
+        assert_eq!(result[3].payload, Payload::JumpIfNotZero('y', 1));
+        let cpu = run_program(&lines);
+        assert_eq!(cpu.get_register_x(), 8);
+        assert_eq!(cpu.get_register('y'), 0);
+        assert_eq!(cpu.get_cycle_number(), 2 + 3 * (4 + 2 + 2));
+    }
+
+    #[test]
+    fn test_label_before_instruction() {
+        // Given
+        let lines = [
+            "addy 3",
+            "loop: mulx 2 # label and instruction on the same line",
+            "      addy -1",
+            "      jnzy loop",
+        ];
+
+        // When
+        let result = Assembler::default().assemble(lines);
+
+        // Then
+        assert_eq!(result.len(), 4);
+        assert_eq!(result[3].payload, Payload::JumpIfNotZero('y', 1));
+        let cpu = run_program(&lines);
+        assert_eq!(cpu.get_register_x(), 8);
+    }
+
+    #[test]
+    fn test_jump_to_end() {
+        // Given
+        let lines = ["jmp end", "addx 100", "end:"];
+
+        // When
+        let cpu = run_program(&lines);
+
+        // Then
+        assert_eq!(cpu.get_register_x(), 1);
+        assert_eq!(cpu.get_cycle_number(), 1);
+    }
+
+    #[test]
+    fn test_custom_cycle_costs() {
+        // Given
+        let mut instruction_set = InstructionSet::new();
+        instruction_set.set_nb_cycles(Opcode::Add, 1);
+        let assembler = Assembler::new(instruction_set);
+
+        // When
+        let result = assembler.assemble(["addx 1", "addx 2", "noop"]);
+
+        // Then
+        assert_eq!(
+            result
+                .iter()
+                .map(|instruction| instruction.nb_cycles)
+                .collect::<Vec<usize>>(),
+            vec![1, 1, 1]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unknown_label() {
+        // When
+        Assembler::default().assemble(["jmp nowhere"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duplicate_label() {
+        // When
+        Assembler::default().assemble(["start:", "noop", "start:"]);
+    }
+}
+
+fn run_program(lines: &[&str]) -> Cpu {
+    let program = Assembler::default().assemble(lines.iter().copied());
+    let mut cpu = Cpu::with_program(program);
+    cpu.run(&mut Trace::new());
+    cpu
+}
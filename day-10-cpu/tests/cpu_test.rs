@@ -0,0 +1,134 @@
+use day_10_cpu::{Cpu, Instruction};
+
+mod run {
+    use crate::build_cpu;
+    use day_10_cpu::{CycleEvent, RunOutcome, Trace};
+
+    #[test]
+    fn test_trace() {
+        // Given
+        let mut cpu = build_cpu(&["noop", "addx 3", "addx -5"]);
+        let mut trace = Trace::new();
+
+        // When
+        let result = cpu.run(&mut trace);
+
+        // Then
+        assert_eq!(result, RunOutcome::Finished);
+        assert_eq!(
+            trace.events,
+            vec![
+                CycleEvent { cycle: 1, x: 1 },
+                CycleEvent { cycle: 2, x: 1 },
+                CycleEvent { cycle: 3, x: 1 },
+                CycleEvent { cycle: 4, x: 4 },
+                CycleEvent { cycle: 5, x: 4 },
+            ]
+        );
+        assert_eq!(cpu.get_register_x(), -1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_load_while_executing() {
+        // Given
+        let mut cpu = build_cpu(&["noop", "noop"]);
+        cpu.do_cycle(&mut Trace::new());
+
+        // When
+        cpu.add_instruction(day_10_cpu::Instruction::parse("noop"));
+    }
+}
+
+mod breakpoints {
+    use crate::build_cpu;
+    use day_10_cpu::{CycleBreakpoint, Observers, RunOutcome, Trace, XChangeBreakpoint};
+
+    #[test]
+    fn test_stop_at_cycle() {
+        // Given
+        let mut cpu = build_cpu(&["noop", "addx 3", "addx -5"]);
+        let mut breakpoint = CycleBreakpoint::new(3);
+
+        // When
+        let first_run = cpu.run(&mut breakpoint);
+        let second_run = cpu.run(&mut breakpoint);
+
+        // Then
+        assert_eq!(first_run, RunOutcome::Break(3));
+        assert_eq!(second_run, RunOutcome::Finished);
+        assert_eq!(cpu.get_cycle_number(), 5);
+    }
+
+    #[test]
+    fn test_stop_when_x_changes() {
+        // Given
+        let mut cpu = build_cpu(&["noop", "addx 3", "addx -5"]);
+        let mut breakpoint = XChangeBreakpoint::new();
+
+        // When
+        let result = cpu.run(&mut breakpoint);
+
+        // Then
+        assert_eq!(result, RunOutcome::Break(4));
+    }
+
+    #[test]
+    fn test_combined_observers() {
+        // Given
+        let mut cpu = build_cpu(&["noop", "addx 3", "addx -5"]);
+        let mut trace = Trace::new();
+        let mut breakpoint = XChangeBreakpoint::new();
+        let mut observers = Observers::new();
+        observers.add(&mut trace);
+        observers.add(&mut breakpoint);
+
+        // When
+        let result = cpu.run(&mut observers);
+
+        // Then
+        assert_eq!(result, RunOutcome::Break(4));
+        assert_eq!(trace.events.len(), 4);
+        assert_eq!(trace.render().lines().last(), Some("cycle    4: X = 4"));
+    }
+}
+
+mod signal_strength {
+    use crate::build_cpu;
+    use day_10_cpu::SignalStrength;
+
+    #[test]
+    fn test_custom_sampling() {
+        // Given
+        let mut cpu = build_cpu(&["noop", "addx 3", "addx -5"]);
+        let mut signal_strength = SignalStrength::new(2, 2);
+
+        // When
+        cpu.run(&mut signal_strength);
+
+        // Then
+        assert_eq!(signal_strength.signals, vec![2, 16]);
+        assert_eq!(signal_strength.get_sum(), 18);
+    }
+
+    #[test]
+    fn test_default_sampling() {
+        // Given
+        let mut cpu = build_cpu(&["addx 1"; 40]);
+        let mut signal_strength = SignalStrength::default();
+
+        // When
+        cpu.run(&mut signal_strength);
+
+        // Then
+        assert_eq!(signal_strength.signals, vec![20 * 10, 60 * 30]);
+    }
+}
+
+fn build_cpu(lines: &[&str]) -> Cpu {
+    let mut cpu = Cpu::new();
+    lines
+        .iter()
+        .for_each(|line| cpu.add_instruction(Instruction::parse(line)));
+    cpu
+}
@@ -0,0 +1,108 @@
+use grid::Grid;
+
+mod read_letters {
+    use crate::build_rows;
+    use day_10_cpu::read_letters;
+    use grid::Pos;
+
+    #[test]
+    fn test_known_letters() {
+        // Given
+        let rows = build_rows(&[
+            "#..#.####.#....###..",
+            "#..#.#....#....#..#.",
+            "####.###..#....#..#.",
+            "#..#.#....#....###..",
+            "#..#.#....#....#.#..",
+            "#..#.####.####.#..#.",
+        ]);
+
+        // When
+        let result = read_letters(&rows, '#');
+
+        // Then
+        assert_eq!(result, Ok(String::from("HELR")));
+    }
+
+    #[test]
+    fn test_several_lines() {
+        // Given
+        let rows = build_rows(&[
+            ".##..", "#..#.", "#..#.", "####.", "#..#.", "#..#.", "####.", "...#.", "..#..",
+            ".#...", "#....", "####.",
+        ]);
+
+        // When
+        let result = read_letters(&rows, '#');
+
+        // Then
+        assert_eq!(result, Ok(String::from("A\nZ")));
+    }
+
+    #[test]
+    fn test_unrecognized_glyphs() {
+        // Given
+        let rows = build_rows(&[
+            "####.####.####.",
+            "#.......#.#....",
+            "###....#..###..",
+            "#.....#...#....",
+            "#....#....#....",
+            "#....####.#..#.",
+        ]);
+
+        // When
+        let result = read_letters(&rows, '#').unwrap_err();
+
+        // Then
+        assert_eq!(result.text, "FZ?");
+        assert_eq!(result.positions, vec![Pos::new(10, 0)]);
+        assert_eq!(
+            result.to_string(),
+            "Could not read `FZ?`, unrecognized glyphs at (10, 0)"
+        );
+    }
+}
+
+mod screen_read_letters {
+    use day_10_cpu::{Overflow, Screen, ScreenConfig, SCREEN_WIDTH};
+
+    #[test]
+    fn test_blank_screen() {
+        // Given
+        let mut screen = Screen::new();
+        for _ in 0..SCREEN_WIDTH * 6 {
+            screen.draw_pixel(-10);
+        }
+
+        // When
+        let result = screen.read_letters();
+
+        // Then
+        assert_eq!(result, Ok(String::new()));
+    }
+
+    #[test]
+    fn test_narrower_than_glyph() {
+        // Given
+        let mut screen = Screen::with_config(&ScreenConfig {
+            width: 3,
+            height: 6,
+            sprite_width: 3,
+            overflow: Overflow::Wrap,
+        });
+        for _ in 0..3 * 6 {
+            screen.draw_pixel(1);
+        }
+
+        // When
+        let result = screen.read_letters();
+
+        // Then
+        assert_eq!(result, Ok(String::new()));
+    }
+}
+
+fn build_rows(lines: &[&str]) -> Grid<char> {
+    Grid::parse(lines, |_, char| char)
+}
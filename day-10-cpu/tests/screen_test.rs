@@ -0,0 +1,199 @@
+mod is_pixel_in_sprite {
+    use day_10_cpu::Screen;
+
+    #[test]
+    fn test_sprite_around_middle() {
+        // Given
+        let mut screen = Screen::new();
+        let sprite_middle_x = 2;
+
+        // When
+        let mut result = Vec::new();
+        for _ in 0..5 {
+            result.push(screen.is_pixel_in_sprite(sprite_middle_x));
+            screen.move_rtc();
+        }
+
+        // Then
+        assert_eq!(result, vec![false, true, true, true, false]);
+        assert_eq!(screen.get_rtc_x(), 5);
+    }
+}
+
+mod on_cycle {
+    use day_10_cpu::{Cpu, Instruction, Screen, PIXEL_OFF, PIXEL_ON};
+
+    #[test]
+    fn test_draw_first_pixels() {
+        // Given
+        let mut cpu = Cpu::new();
+        ["addx 15", "addx -11", "addx 6", "addx -3", "addx 5"]
+            .iter()
+            .for_each(|line| cpu.add_instruction(Instruction::parse(line)));
+        let mut screen = Screen::new();
+
+        // When
+        cpu.run(&mut screen);
+
+        // Then
+        let first_row: String = screen.rows.row(0).iter().take(10).collect();
+        assert_eq!(
+            first_row,
+            [
+                PIXEL_ON, PIXEL_ON, PIXEL_OFF, PIXEL_OFF, PIXEL_ON, PIXEL_ON, PIXEL_OFF, PIXEL_OFF,
+                PIXEL_ON, PIXEL_ON
+            ]
+            .iter()
+            .collect::<String>()
+        );
+    }
+}
+
+mod overflow {
+    use crate::draw_all;
+    use day_10_cpu::{Overflow, Screen, ScreenConfig};
+
+    #[test]
+    fn test_wrap() {
+        // Given
+        let mut screen = Screen::with_config(&ScreenConfig {
+            width: 4,
+            height: 2,
+            sprite_width: 1,
+            overflow: Overflow::Wrap,
+        });
+
+        // When
+        draw_all(&mut screen, &[0, 0, 0, 0, 1, 1, 1, 1, 0, 1]);
+
+        // Then
+        assert_eq!(screen.rows.get_height(), 2);
+        assert_eq!(screen.rows.row(0), &['X', 'X', ' ', ' ']);
+        assert_eq!(screen.rows.row(1), &[' ', 'X', ' ', ' ']);
+        assert_eq!((screen.get_rtc_x(), screen.get_rtc_y()), (2, 0));
+    }
+
+    #[test]
+    fn test_grow() {
+        // Given
+        let mut screen = Screen::with_config(&ScreenConfig {
+            width: 4,
+            height: 2,
+            sprite_width: 1,
+            overflow: Overflow::Grow,
+        });
+
+        // When
+        draw_all(&mut screen, &[0, 0, 0, 0, 1, 1, 1, 1, 2, 2]);
+
+        // Then
+        assert_eq!(screen.rows.get_height(), 3);
+        assert_eq!(screen.rows.row(0), &['X', ' ', ' ', ' ']);
+        assert_eq!(screen.rows.row(2), &[' ', ' ', ' ', ' ']);
+        assert_eq!((screen.get_rtc_x(), screen.get_rtc_y()), (2, 2));
+    }
+
+    #[test]
+    fn test_grow_filled_exactly() {
+        // Given
+        let config = ScreenConfig {
+            width: 4,
+            height: 2,
+            sprite_width: 1,
+            overflow: Overflow::Grow,
+        };
+        let mut screen = Screen::with_config(&config);
+
+        // When
+        draw_all(&mut screen, &[0, 0, 0, 0, 1, 1, 1, 1]);
+
+        // Then
+        assert_eq!(screen.rows.get_height(), config.height);
+        assert_eq!(screen.rows.row(1), &[' ', 'X', ' ', ' ']);
+        assert_eq!((screen.get_rtc_x(), screen.get_rtc_y()), (0, 2));
+    }
+
+    #[test]
+    fn test_wide_sprite() {
+        // Given
+        let mut screen = Screen::with_config(&ScreenConfig {
+            width: 6,
+            height: 1,
+            sprite_width: 5,
+            overflow: Overflow::Wrap,
+        });
+
+        // When
+        draw_all(&mut screen, &[3; 6]);
+
+        // Then
+        assert_eq!(screen.rows.row(0), &[' ', 'X', 'X', 'X', 'X', 'X']);
+    }
+}
+
+mod parse_overflow {
+    use day_10_cpu::Overflow;
+
+    #[test]
+    fn test_valid() {
+        // When
+        let result = ["wrap", "grow"].map(Overflow::parse);
+
+        // Then
+        assert_eq!(result, [Overflow::Wrap, Overflow::Grow]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid overflow: `scroll`")]
+    fn test_invalid() {
+        // When
+        Overflow::parse("scroll");
+    }
+}
+
+mod export {
+    use crate::draw_all;
+    use day_10_cpu::{Overflow, Screen, ScreenConfig};
+
+    #[test]
+    fn test_to_pbm() {
+        // Given
+        let mut screen = Screen::with_config(&ScreenConfig {
+            width: 3,
+            height: 2,
+            sprite_width: 1,
+            overflow: Overflow::Wrap,
+        });
+        draw_all(&mut screen, &[0, 0, 0, 2, 1, 2]);
+
+        // When
+        let result = screen.to_pbm();
+
+        // Then
+        assert_eq!(result, "P1\n3 2\n1 0 0\n0 1 1\n");
+    }
+
+    #[test]
+    fn test_to_pgm() {
+        // Given
+        let mut screen = Screen::with_config(&ScreenConfig {
+            width: 3,
+            height: 1,
+            sprite_width: 1,
+            overflow: Overflow::Wrap,
+        });
+        draw_all(&mut screen, &[1, 1, 1]);
+
+        // When
+        let result = screen.to_pgm();
+
+        // Then
+        assert_eq!(result, "P2\n3 1\n255\n0 255 0\n");
+    }
+}
+
+fn draw_all(screen: &mut day_10_cpu::Screen, sprite_positions: &[isize]) {
+    sprite_positions
+        .iter()
+        .for_each(|sprite_middle_x| screen.draw_pixel(*sprite_middle_x));
+}
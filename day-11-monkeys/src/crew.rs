@@ -0,0 +1,159 @@
+use std::cell::RefCell;
+
+use crate::item::{Relief, WorryLevel};
+use crate::monkey::{Flyingitem, Monkey};
+use crate::stats::RoundStats;
+
+pub const NB_ACTIVE_MONKEYS: usize = 2;
+
+pub struct MonkeysCrew {
+    pub monkeys: Vec<RefCell<Monkey>>,
+    relief: Relief,
+    lcm: WorryLevel,
+    prepared: bool,
+    round_number: usize,
+    trace: Option<Vec<RoundStats>>,
+}
+
+impl MonkeysCrew {
+    pub fn new(relief: Relief) -> Self {
+        MonkeysCrew {
+            monkeys: Vec::new(),
+            relief,
+            lcm: 1,
+            prepared: false,
+            round_number: 0,
+            trace: None,
+        }
+    }
+
+    pub fn from_monkeys(relief: Relief, monkeys: Vec<Monkey>) -> Self {
+        let mut crew = Self::new(relief);
+        monkeys
+            .into_iter()
+            .for_each(|monkey| crew.add_monkey(monkey));
+        crew
+    }
+
+    pub fn get_relief(&self) -> Relief {
+        self.relief
+    }
+
+    pub fn get_round_number(&self) -> usize {
+        self.round_number
+    }
+
+    // Records the state of every monkey after each round from now on
+    pub fn enable_trace(&mut self) {
+        if self.trace.is_none() {
+            self.trace = Some(Vec::new());
+        }
+    }
+
+    pub fn get_trace(&self) -> &[RoundStats] {
+        match &self.trace {
+            Some(trace) => trace,
+            None => &[],
+        }
+    }
+
+    pub fn add_monkey(&mut self, monkey: Monkey) {
+        if self.prepared {
+            panic!("Cannot add a monkey once the game has started");
+        }
+        self.monkeys.push(RefCell::new(monkey));
+    }
+
+    pub fn get_test_values(&self) -> Vec<WorryLevel> {
+        self.monkeys
+            .iter()
+            .map(|monkey| monkey.borrow().test_value)
+            .collect()
+    }
+
+    pub(crate) fn prepare_if_needed(&mut self) {
+        if !self.prepared {
+            self.prepare();
+        }
+    }
+
+    pub(crate) fn get_lcm(&self) -> WorryLevel {
+        self.lcm
+    }
+
+    pub(crate) fn get_modulus(&self) -> Option<WorryLevel> {
+        match self.relief {
+            Relief::ModuloLcm => Some(self.lcm),
+            _ => None,
+        }
+    }
+
+    fn prepare(&mut self) {
+        let test_values = self.get_test_values();
+        self.lcm = test_values.iter().fold(1, |lcm, test_value| {
+            lcm / get_gcd(lcm, *test_value) * test_value
+        });
+        self.monkeys.iter().for_each(|monkey| {
+            monkey
+                .borrow_mut()
+                .items
+                .iter_mut()
+                .for_each(|item| item.prepare(&self.relief, &test_values, self.lcm))
+        });
+        self.prepared = true;
+    }
+
+    pub fn do_round(&mut self) {
+        self.prepare_if_needed();
+        self.monkeys.iter().for_each(|monkey_ref| {
+            while monkey_ref.borrow().has_items_left() {
+                let Flyingitem {
+                    item,
+                    target_monkey_id,
+                } = {
+                    let mut monkey = monkey_ref.borrow_mut();
+                    monkey.take_item();
+                    monkey.manipulate_item(self.get_modulus());
+                    monkey
+                        .get_manipulated_item_mut()
+                        .relieve(&self.relief, self.lcm);
+                    monkey.throw_item()
+                };
+                let receiver_monkey = self.monkeys.get(target_monkey_id).unwrap();
+                receiver_monkey.borrow_mut().receive_item(item);
+            }
+        });
+        self.round_number += 1;
+
+        if let Some(mut trace) = self.trace.take() {
+            trace.push(RoundStats::from_crew(self, self.round_number));
+            self.trace = Some(trace);
+        }
+    }
+
+    pub fn do_rounds(&mut self, nb_rounds: usize) {
+        for _ in 0..nb_rounds {
+            self.do_round();
+        }
+    }
+
+    pub fn get_manipulations_counts(&self) -> Vec<usize> {
+        self.monkeys
+            .iter()
+            .map(|monkey| monkey.borrow().manipulations_count)
+            .collect()
+    }
+
+    pub fn get_monkey_business(&self, nb_active_monkeys: usize) -> usize {
+        let mut counts = self.get_manipulations_counts();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts.iter().take(nb_active_monkeys).product()
+    }
+}
+
+fn get_gcd(a: WorryLevel, b: WorryLevel) -> WorryLevel {
+    match b {
+        0 => a,
+        _ => get_gcd(b, a % b),
+    }
+}
@@ -0,0 +1,153 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::item::WorryLevel;
+
+const OLD_WORD: &str = "old";
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Expression {
+    Old,
+    Constant(WorryLevel),
+    Add(Box<Expression>, Box<Expression>),
+    Sub(Box<Expression>, Box<Expression>),
+    Mul(Box<Expression>, Box<Expression>),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Token {
+    Old,
+    Number(WorryLevel),
+    Plus,
+    Minus,
+    Star,
+    OpenParenthesis,
+    CloseParenthesis,
+}
+
+impl Expression {
+    // Grammar, from lowest to highest precedence:
+    //   expression = term (("+" | "-") term)*
+    //   term       = factor ("*" factor)*
+    //   factor     = "old" | number | "(" expression ")"
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let tokens = tokenize(text)?;
+        let mut tokens = tokens.into_iter().peekable();
+        let expression = parse_expression(&mut tokens)?;
+        match tokens.next() {
+            None => Ok(expression),
+            Some(token) => Err(format!("Unexpected token `{:?}` in `{}`", token, text)),
+        }
+    }
+
+    pub fn evaluate(&self, old: WorryLevel) -> WorryLevel {
+        match self {
+            Expression::Old => old,
+            Expression::Constant(value) => *value,
+            Expression::Add(left, right) => left.evaluate(old) + right.evaluate(old),
+            Expression::Sub(left, right) => left
+                .evaluate(old)
+                .checked_sub(right.evaluate(old))
+                .unwrap_or_else(|| panic!("Worry level of `{}` became negative", old)),
+            Expression::Mul(left, right) => left.evaluate(old) * right.evaluate(old),
+        }
+    }
+
+    // IF a ≡ b (n) THEN a+c ≡ b+c (n), a-c ≡ b-c (n) and ac ≡ bc (n)
+    pub fn evaluate_modulo(&self, old: WorryLevel, modulus: WorryLevel) -> WorryLevel {
+        match self {
+            Expression::Old => old % modulus,
+            Expression::Constant(value) => value % modulus,
+            Expression::Add(left, right) => {
+                (left.evaluate_modulo(old, modulus) + right.evaluate_modulo(old, modulus)) % modulus
+            }
+            Expression::Sub(left, right) => {
+                (left.evaluate_modulo(old, modulus) + modulus - right.evaluate_modulo(old, modulus))
+                    % modulus
+            }
+            Expression::Mul(left, right) => {
+                let product = left.evaluate_modulo(old, modulus) as u128
+                    * right.evaluate_modulo(old, modulus) as u128;
+                (product % modulus as u128) as WorryLevel
+            }
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars: Peekable<Chars> = text.chars().peekable();
+    while let Some(char) = chars.next() {
+        let token = match char {
+            ' ' => continue,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '(' => Token::OpenParenthesis,
+            ')' => Token::CloseParenthesis,
+            '0'..='9' => {
+                let mut number = char.to_string();
+                while let Some(digit) = chars.next_if(|c| c.is_ascii_digit()) {
+                    number.push(digit);
+                }
+                Token::Number(
+                    number
+                        .parse()
+                        .map_err(|_| format!("Invalid number `{}`", number))?,
+                )
+            }
+            _ if char.is_ascii_alphabetic() => {
+                let mut word = char.to_string();
+                while let Some(letter) = chars.next_if(|c| c.is_ascii_alphabetic()) {
+                    word.push(letter);
+                }
+                match word == OLD_WORD {
+                    true => Token::Old,
+                    false => return Err(format!("Unknown variable `{}`", word)),
+                }
+            }
+            _ => return Err(format!("Invalid character `{}`", char)),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+type Tokens = Peekable<std::vec::IntoIter<Token>>;
+
+fn parse_expression(tokens: &mut Tokens) -> Result<Expression, String> {
+    let mut expression = parse_term(tokens)?;
+    while let Some(token) = tokens.next_if(|t| *t == Token::Plus || *t == Token::Minus) {
+        let right = Box::new(parse_term(tokens)?);
+        expression = match token {
+            Token::Plus => Expression::Add(Box::new(expression), right),
+            _ => Expression::Sub(Box::new(expression), right),
+        };
+    }
+    Ok(expression)
+}
+
+fn parse_term(tokens: &mut Tokens) -> Result<Expression, String> {
+    let mut expression = parse_factor(tokens)?;
+    while tokens.next_if_eq(&Token::Star).is_some() {
+        let right = Box::new(parse_factor(tokens)?);
+        expression = Expression::Mul(Box::new(expression), right);
+    }
+    Ok(expression)
+}
+
+fn parse_factor(tokens: &mut Tokens) -> Result<Expression, String> {
+    match tokens.next() {
+        Some(Token::Old) => Ok(Expression::Old),
+        Some(Token::Number(value)) => Ok(Expression::Constant(value)),
+        Some(Token::OpenParenthesis) => {
+            let expression = parse_expression(tokens)?;
+            match tokens.next() {
+                Some(Token::CloseParenthesis) => Ok(expression),
+                _ => Err(String::from("Missing closing parenthesis")),
+            }
+        }
+        Some(token) => Err(format!("Unexpected token `{:?}`", token)),
+        None => Err(String::from("Unexpected end of expression")),
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use crate::crew::MonkeysCrew;
+use crate::item::{Item, Worry};
+use crate::monkey::MonkeyId;
+
+pub type InspectionCount = u128;
+
+impl MonkeysCrew {
+    // Inspection counts after `nb_rounds` more rounds, computed item by item:
+    // an item's trajectory only depends on the monkey holding it and its worry
+    // level at the start of a round, so it eventually loops and can be
+    // extrapolated. The crew itself is left untouched.
+    pub fn fast_forward(&mut self, nb_rounds: usize) -> Vec<InspectionCount> {
+        self.prepare_if_needed();
+        let mut counts: Vec<InspectionCount> = self
+            .get_manipulations_counts()
+            .iter()
+            .map(|count| *count as InspectionCount)
+            .collect();
+
+        self.monkeys
+            .iter()
+            .enumerate()
+            .for_each(|(monkey_id, monkey)| {
+                monkey.borrow().items.iter().for_each(|item| {
+                    let item_counts = self.get_item_counts(monkey_id, item.clone(), nb_rounds);
+                    counts
+                        .iter_mut()
+                        .zip(item_counts)
+                        .for_each(|(count, item_count)| *count += item_count);
+                })
+            });
+        counts
+    }
+
+    pub fn get_monkey_business_after(
+        &mut self,
+        nb_rounds: usize,
+        nb_active_monkeys: usize,
+    ) -> InspectionCount {
+        let mut counts = self.fast_forward(nb_rounds);
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts.iter().take(nb_active_monkeys).product()
+    }
+
+    fn get_item_counts(
+        &self,
+        monkey_id: MonkeyId,
+        item: Item,
+        nb_rounds: usize,
+    ) -> Vec<InspectionCount> {
+        let nb_monkeys = self.monkeys.len();
+        // Cumulated counts at the start of each round
+        let mut history = vec![vec![0; nb_monkeys]];
+        let mut seen_states: HashMap<(MonkeyId, Worry), usize> = HashMap::new();
+        let mut state = (monkey_id, item);
+
+        for round in 0..nb_rounds {
+            let key = (state.0, state.1.worry.clone());
+            if let Some(cycle_start) = seen_states.insert(key, round) {
+                return extrapolate(&history, cycle_start, round, nb_rounds);
+            }
+            let mut counts = history[round].clone();
+            state = self.do_item_round(state, &mut counts);
+            history.push(counts);
+        }
+        history.pop().unwrap()
+    }
+
+    // An item keeps moving during the round while it is thrown to monkeys
+    // which have not played yet
+    fn do_item_round(
+        &self,
+        (mut monkey_id, mut item): (MonkeyId, Item),
+        counts: &mut [InspectionCount],
+    ) -> (MonkeyId, Item) {
+        loop {
+            let monkey = self.monkeys[monkey_id].borrow();
+            monkey.operation.manipulate(&mut item, self.get_modulus());
+            item.relieve(&self.get_relief(), self.get_lcm());
+            counts[monkey_id] += 1;
+
+            let target_monkey_id = monkey.get_target_for(&item);
+            if target_monkey_id < monkey_id {
+                return (target_monkey_id, item);
+            }
+            monkey_id = target_monkey_id;
+        }
+    }
+}
+
+fn extrapolate(
+    history: &[Vec<InspectionCount>],
+    cycle_start: usize,
+    cycle_end: usize,
+    nb_rounds: usize,
+) -> Vec<InspectionCount> {
+    let cycle_length = cycle_end - cycle_start;
+    let nb_cycles = ((nb_rounds - cycle_start) / cycle_length) as InspectionCount;
+    let remaining_rounds = (nb_rounds - cycle_start) % cycle_length;
+
+    (0..history[0].len())
+        .map(|monkey_id| {
+            let start_count = history[cycle_start][monkey_id];
+            let cycle_count = history[cycle_end][monkey_id] - start_count;
+            let remaining_count = history[cycle_start + remaining_rounds][monkey_id] - start_count;
+            start_count + nb_cycles * cycle_count + remaining_count
+        })
+        .collect()
+}
@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+
+pub type WorryLevel = u64;
+
+pub const INTEREST_LOSS_FACTOR: WorryLevel = 3;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Relief {
+    // Worry levels are divided after each inspection, as in part 1
+    DivideBy(WorryLevel),
+    // Worry levels are kept modulo the LCM of every test value
+    ModuloLcm,
+    // Only the residue modulo each test value is kept
+    Residues,
+}
+
+impl Relief {
+    pub fn parse(word: &str) -> Self {
+        match word {
+            "divide" => Relief::DivideBy(INTEREST_LOSS_FACTOR),
+            "lcm" => Relief::ModuloLcm,
+            "residues" => Relief::Residues,
+            _ => panic!("Invalid relief policy: `{}`", word),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Worry {
+    Level(WorryLevel),
+    Residues(BTreeMap<WorryLevel, WorryLevel>),
+}
+
+impl Worry {
+    pub fn is_divisible_by(&self, test_value: WorryLevel) -> bool {
+        match self {
+            Worry::Level(level) => level % test_value == 0,
+            Worry::Residues(residues) => {
+                let congruence = residues
+                    .get(&test_value)
+                    .unwrap_or_else(|| panic!("No residue for test value `{}`", test_value));
+                *congruence == 0
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Item {
+    pub initial_level: WorryLevel,
+    pub worry: Worry,
+}
+
+impl Item {
+    pub fn new(initial_level: WorryLevel) -> Self {
+        Item {
+            initial_level,
+            worry: Worry::Level(initial_level),
+        }
+    }
+
+    pub fn prepare(&mut self, relief: &Relief, test_values: &[WorryLevel], lcm: WorryLevel) {
+        self.worry = match relief {
+            Relief::DivideBy(_) => Worry::Level(self.initial_level),
+            Relief::ModuloLcm => Worry::Level(self.initial_level % lcm),
+            Relief::Residues => Worry::Residues(
+                test_values
+                    .iter()
+                    .map(|test_value| (*test_value, self.initial_level % test_value))
+                    .collect(),
+            ),
+        };
+    }
+
+    pub fn relieve(&mut self, relief: &Relief, lcm: WorryLevel) {
+        match (relief, &mut self.worry) {
+            (Relief::DivideBy(factor), Worry::Level(level)) => *level /= factor,
+            (Relief::ModuloLcm, Worry::Level(level)) => *level %= lcm,
+            (Relief::Residues, Worry::Residues(_)) => (),
+            _ => panic!("Item was not prepared for relief `{:?}`", relief),
+        }
+    }
+}
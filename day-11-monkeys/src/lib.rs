@@ -0,0 +1,15 @@
+mod crew;
+mod expression;
+mod fast_forward;
+mod item;
+mod monkey;
+mod parser;
+mod stats;
+
+pub use crew::{MonkeysCrew, NB_ACTIVE_MONKEYS};
+pub use expression::Expression;
+pub use fast_forward::InspectionCount;
+pub use item::{Item, Relief, Worry, WorryLevel, INTEREST_LOSS_FACTOR};
+pub use monkey::{Flyingitem, Monkey, MonkeyId, Operation};
+pub use parser::{parse_monkeys, ParseError};
+pub use stats::{to_csv, MonkeyStats, RoundStats};
@@ -0,0 +1,122 @@
+use std::collections::VecDeque;
+
+use crate::expression::Expression;
+use crate::item::{Item, Worry, WorryLevel};
+
+pub type MonkeyId = usize;
+
+const OPERATION_PREFIX: &str = "new =";
+
+#[derive(Debug)]
+pub struct Operation {
+    pub expression: Expression,
+}
+
+impl Operation {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let expression = text
+            .trim()
+            .strip_prefix(OPERATION_PREFIX)
+            .ok_or_else(|| format!("Operation should start with `{}`", OPERATION_PREFIX))?;
+        Ok(Operation {
+            expression: Expression::parse(expression)?,
+        })
+    }
+
+    // Plain worry levels are computed modulo `modulus` when there is one
+    pub fn manipulate(&self, item: &mut Item, modulus: Option<WorryLevel>) {
+        match (&mut item.worry, modulus) {
+            (Worry::Level(level), None) => *level = self.expression.evaluate(*level),
+            (Worry::Level(level), Some(modulus)) => {
+                *level = self.expression.evaluate_modulo(*level, modulus)
+            }
+            (Worry::Residues(residues), _) => {
+                residues.iter_mut().for_each(|(test_value, current_value)| {
+                    *current_value = self.expression.evaluate_modulo(*current_value, *test_value);
+                })
+            }
+        }
+    }
+}
+
+pub struct Flyingitem {
+    pub target_monkey_id: MonkeyId,
+    pub item: Item,
+}
+
+#[derive(Debug)]
+pub struct Monkey {
+    pub id: MonkeyId,
+    pub operation: Operation,
+    pub test_value: WorryLevel,
+    pub target_true: MonkeyId,
+    pub target_false: MonkeyId,
+    pub items: VecDeque<Item>,
+    manipulated_item: Option<Item>,
+    pub manipulations_count: usize,
+}
+
+impl Monkey {
+    pub fn new(
+        id: MonkeyId,
+        operation: Operation,
+        test_value: WorryLevel,
+        target_true: MonkeyId,
+        target_false: MonkeyId,
+        items: VecDeque<Item>,
+    ) -> Self {
+        Monkey {
+            id,
+            operation,
+            test_value,
+            target_true,
+            target_false,
+            items,
+            manipulated_item: None,
+            manipulations_count: 0,
+        }
+    }
+
+    pub fn has_items_left(&self) -> bool {
+        !self.items.is_empty()
+    }
+
+    pub fn take_item(&mut self) {
+        let item = self.items.pop_front();
+        self.manipulated_item = item;
+    }
+
+    pub fn manipulate_item(&mut self, modulus: Option<WorryLevel>) {
+        let item = self.manipulated_item.as_mut().unwrap();
+        self.operation.manipulate(item, modulus);
+        self.manipulations_count += 1;
+    }
+
+    pub fn get_manipulated_item_mut(&mut self) -> &mut Item {
+        self.manipulated_item.as_mut().unwrap()
+    }
+
+    pub fn throw_item(&mut self) -> Flyingitem {
+        let target_monkey_id = self.get_target_monkey_id();
+        let item = self.manipulated_item.take().unwrap();
+        Flyingitem {
+            target_monkey_id,
+            item,
+        }
+    }
+
+    fn get_target_monkey_id(&self) -> MonkeyId {
+        self.get_target_for(self.manipulated_item.as_ref().unwrap())
+    }
+
+    pub fn get_target_for(&self, item: &Item) -> MonkeyId {
+        match item.worry.is_divisible_by(self.test_value) {
+            true => self.target_true,
+            false => self.target_false,
+        }
+    }
+
+    pub fn receive_item(&mut self, item: Item) {
+        self.items.push_back(item);
+    }
+}
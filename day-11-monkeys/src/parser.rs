@@ -0,0 +1,204 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::item::{Item, WorryLevel};
+use crate::monkey::{Monkey, MonkeyId, Operation};
+
+const NB_BLOCK_LINES: usize = 6;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    pub block_idx: usize,
+    // 1-based line number in the input, if the error comes from a single line
+    pub line_number: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line_number {
+            Some(line_number) => write!(
+                f,
+                "Monkey block {} (line {}): {}",
+                self.block_idx, line_number, self.message
+            ),
+            None => write!(f, "Monkey block {}: {}", self.block_idx, self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Blocks are separated by blank lines, each one describing a monkey with
+// exactly the six lines of the puzzle input, in order
+pub fn parse_monkeys(text: &str) -> Result<Vec<Monkey>, Vec<ParseError>> {
+    let mut blocks: Vec<Vec<(usize, &str)>> = vec![Vec::new()];
+    text.lines()
+        .enumerate()
+        .for_each(|(line_idx, line)| match line.trim().is_empty() {
+            true => {
+                if !blocks.last().unwrap().is_empty() {
+                    blocks.push(Vec::new());
+                }
+            }
+            false => blocks.last_mut().unwrap().push((line_idx + 1, line.trim())),
+        });
+    if blocks.last().unwrap().is_empty() {
+        blocks.pop();
+    }
+
+    let mut errors = Vec::new();
+    let monkeys: Vec<Monkey> = blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(block_idx, lines)| match parse_block(block_idx, lines) {
+            Ok(monkey) => Some(monkey),
+            Err(block_errors) => {
+                errors.extend(block_errors);
+                None
+            }
+        })
+        .collect();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let errors = validate_monkeys(&monkeys);
+    match errors.is_empty() {
+        true => Ok(monkeys),
+        false => Err(errors),
+    }
+}
+
+fn validate_monkeys(monkeys: &[Monkey]) -> Vec<ParseError> {
+    let mut errors = Vec::new();
+    monkeys.iter().enumerate().for_each(|(block_idx, monkey)| {
+        let mut add_error = |message: String| {
+            errors.push(ParseError {
+                block_idx,
+                line_number: None,
+                message,
+            })
+        };
+        if monkey.id != block_idx {
+            add_error(format!(
+                "Expected monkey `{}` but found monkey `{}`",
+                block_idx, monkey.id
+            ));
+        }
+        [monkey.target_true, monkey.target_false]
+            .iter()
+            .for_each(|target| match *target >= monkeys.len() {
+                true => add_error(format!("Target monkey `{}` does not exist", target)),
+                false => {
+                    if *target == monkey.id {
+                        add_error(format!("Monkey `{}` throws items to itself", target))
+                    }
+                }
+            });
+    });
+    errors
+}
+
+fn parse_block(block_idx: usize, lines: &[(usize, &str)]) -> Result<Monkey, Vec<ParseError>> {
+    if lines.len() != NB_BLOCK_LINES {
+        return Err(vec![ParseError {
+            block_idx,
+            line_number: lines.first().map(|(line_number, _)| *line_number),
+            message: format!(
+                "Expected {} lines but found {}",
+                NB_BLOCK_LINES,
+                lines.len()
+            ),
+        }]);
+    }
+
+    let mut errors = Vec::new();
+    let mut check = |line_idx: usize, result: Result<(), String>| {
+        if let Err(message) = result {
+            errors.push(ParseError {
+                block_idx,
+                line_number: Some(lines[line_idx].0),
+                message,
+            });
+        }
+    };
+
+    let mut id: MonkeyId = 0;
+    let mut items = Vec::new();
+    let mut operation = None;
+    let mut test_value = 0;
+    let mut target_true: MonkeyId = 0;
+    let mut target_false: MonkeyId = 0;
+
+    check(
+        0,
+        parse_field(lines[0].1, "Monkey ", ":").map(|value| id = value),
+    );
+    check(1, parse_items(lines[1].1).map(|value| items = value));
+    check(
+        2,
+        strip_field(lines[2].1, "Operation:", "")
+            .and_then(Operation::parse)
+            .map(|value| operation = Some(value)),
+    );
+    check(
+        3,
+        parse_field(lines[3].1, "Test: divisible by ", "").and_then(
+            |value: WorryLevel| match value {
+                0 => Err(String::from("Test value must be positive")),
+                _ => {
+                    test_value = value;
+                    Ok(())
+                }
+            },
+        ),
+    );
+    check(
+        4,
+        parse_field(lines[4].1, "If true: throw to monkey ", "").map(|value| target_true = value),
+    );
+    check(
+        5,
+        parse_field(lines[5].1, "If false: throw to monkey ", "").map(|value| target_false = value),
+    );
+
+    match operation {
+        Some(operation) if errors.is_empty() => Ok(Monkey::new(
+            id,
+            operation,
+            test_value,
+            target_true,
+            target_false,
+            items.into_iter().map(Item::new).collect(),
+        )),
+        _ => Err(errors),
+    }
+}
+
+fn strip_field<'a>(line: &'a str, prefix: &str, suffix: &str) -> Result<&'a str, String> {
+    line.strip_prefix(prefix)
+        .and_then(|rest| rest.strip_suffix(suffix))
+        .ok_or_else(|| format!("Expected `{}...{}`, found `{}`", prefix, suffix, line))
+}
+
+fn parse_field<T: FromStr>(line: &str, prefix: &str, suffix: &str) -> Result<T, String> {
+    let value = strip_field(line, prefix, suffix)?;
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid number `{}`", value))
+}
+
+fn parse_items(line: &str) -> Result<Vec<WorryLevel>, String> {
+    let items = strip_field(line, "Starting items:", "")?;
+    items
+        .split(',')
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            word.parse()
+                .map_err(|_| format!("Invalid worry level `{}`", word))
+        })
+        .collect()
+}
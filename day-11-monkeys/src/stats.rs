@@ -0,0 +1,96 @@
+use std::fmt;
+
+use crate::crew::MonkeysCrew;
+use crate::item::Worry;
+use crate::monkey::MonkeyId;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MonkeyStats {
+    pub id: MonkeyId,
+    pub items: Vec<Worry>,
+    pub manipulations_count: usize,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RoundStats {
+    pub round: usize,
+    pub monkeys: Vec<MonkeyStats>,
+}
+
+impl fmt::Display for Worry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Worry::Level(level) => write!(f, "{}", level),
+            Worry::Residues(residues) => {
+                let residues = residues
+                    .iter()
+                    .map(|(test_value, residue)| format!("{}%{}", residue, test_value))
+                    .collect::<Vec<String>>()
+                    .join("|");
+                write!(f, "{}", residues)
+            }
+        }
+    }
+}
+
+impl RoundStats {
+    pub fn from_crew(crew: &MonkeysCrew, round: usize) -> Self {
+        let monkeys = crew
+            .monkeys
+            .iter()
+            .map(|monkey| {
+                let monkey = monkey.borrow();
+                MonkeyStats {
+                    id: monkey.id,
+                    items: monkey.items.iter().map(|item| item.worry.clone()).collect(),
+                    manipulations_count: monkey.manipulations_count,
+                }
+            })
+            .collect();
+        RoundStats { round, monkeys }
+    }
+
+    pub fn render_items(&self) -> String {
+        let mut lines = vec![format!(
+            "After round {}, the monkeys are holding items with these worry levels:",
+            self.round
+        )];
+        self.monkeys.iter().for_each(|monkey| {
+            let items = monkey
+                .items
+                .iter()
+                .map(|worry| worry.to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+            lines.push(format!("Monkey {}: {}", monkey.id, items));
+        });
+        lines.join("\n")
+    }
+
+    pub fn render_inspections(&self) -> String {
+        let mut lines = vec![format!("== After round {} ==", self.round)];
+        self.monkeys.iter().for_each(|monkey| {
+            lines.push(format!(
+                "Monkey {} inspected items {} times.",
+                monkey.id, monkey.manipulations_count
+            ));
+        });
+        lines.join("\n")
+    }
+}
+
+pub fn to_csv(trace: &[RoundStats]) -> String {
+    let mut lines = vec![String::from("round,monkey,items_held,inspections")];
+    trace.iter().for_each(|round_stats| {
+        round_stats.monkeys.iter().for_each(|monkey| {
+            lines.push(format!(
+                "{},{},{},{}",
+                round_stats.round,
+                monkey.id,
+                monkey.items.len(),
+                monkey.manipulations_count
+            ));
+        })
+    });
+    lines.join("\n") + "\n"
+}
@@ -0,0 +1,36 @@
+// Shared by several test crates, each of them only uses part of it
+#![allow(dead_code)]
+
+use day_11_monkeys::{parse_monkeys, MonkeysCrew, Relief};
+
+pub fn build_crew(relief: Relief) -> MonkeysCrew {
+    MonkeysCrew::from_monkeys(relief, parse_monkeys(EXAMPLE).unwrap())
+}
+
+pub const EXAMPLE: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
@@ -0,0 +1,90 @@
+mod common;
+
+mod get_monkey_business {
+    use crate::common::build_crew;
+    use day_11_monkeys::{Relief, INTEREST_LOSS_FACTOR};
+
+    #[test]
+    fn test_divide_relief() {
+        // Given
+        let mut crew = build_crew(Relief::DivideBy(INTEREST_LOSS_FACTOR));
+
+        // When
+        crew.do_rounds(20);
+
+        // Then
+        assert_eq!(crew.get_manipulations_counts(), vec![101, 95, 7, 105]);
+        assert_eq!(crew.get_monkey_business(2), 10605);
+    }
+
+    #[test]
+    fn test_lcm_relief() {
+        // Given
+        let mut crew = build_crew(Relief::ModuloLcm);
+
+        // When
+        crew.do_rounds(10000);
+
+        // Then
+        assert_eq!(crew.get_monkey_business(2), 2713310158);
+    }
+
+    #[test]
+    fn test_residues_relief() {
+        // Given
+        let mut crew = build_crew(Relief::Residues);
+
+        // When
+        crew.do_rounds(20);
+
+        // Then
+        assert_eq!(crew.get_manipulations_counts(), vec![99, 97, 8, 103]);
+    }
+
+    #[test]
+    fn test_top_n() {
+        // Given
+        let mut crew = build_crew(Relief::Residues);
+
+        // When
+        crew.do_rounds(20);
+
+        // Then
+        assert_eq!(crew.get_monkey_business(1), 103);
+        assert_eq!(crew.get_monkey_business(3), 103 * 99 * 97);
+    }
+}
+
+mod relief {
+    use crate::common::build_crew;
+    use day_11_monkeys::Relief;
+
+    #[test]
+    fn test_policies_agree_without_division() {
+        // Given
+        let mut lcm_crew = build_crew(Relief::ModuloLcm);
+        let mut residues_crew = build_crew(Relief::Residues);
+
+        // When
+        lcm_crew.do_rounds(1000);
+        residues_crew.do_rounds(1000);
+
+        // Then
+        assert_eq!(
+            lcm_crew.get_manipulations_counts(),
+            residues_crew.get_manipulations_counts()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        // When
+        let result = ["divide", "lcm", "residues"].map(Relief::parse);
+
+        // Then
+        assert_eq!(
+            result,
+            [Relief::DivideBy(3), Relief::ModuloLcm, Relief::Residues]
+        );
+    }
+}
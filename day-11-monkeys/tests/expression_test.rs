@@ -0,0 +1,130 @@
+mod parse {
+    use day_11_monkeys::Expression;
+
+    #[test]
+    fn test_puzzle_operations() {
+        // When
+        let result = ["old * 19", "old + 6", "old * old"].map(Expression::parse);
+
+        // Then
+        assert_eq!(
+            result,
+            [
+                Ok(Expression::Mul(
+                    Box::new(Expression::Old),
+                    Box::new(Expression::Constant(19))
+                )),
+                Ok(Expression::Add(
+                    Box::new(Expression::Old),
+                    Box::new(Expression::Constant(6))
+                )),
+                Ok(Expression::Mul(
+                    Box::new(Expression::Old),
+                    Box::new(Expression::Old)
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_precedence() {
+        // When
+        let result = Expression::parse("3 + old * old").unwrap();
+
+        // Then
+        assert_eq!(
+            result,
+            Expression::Add(
+                Box::new(Expression::Constant(3)),
+                Box::new(Expression::Mul(
+                    Box::new(Expression::Old),
+                    Box::new(Expression::Old)
+                ))
+            )
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        // When
+        let result = ["old / 2", "(old + 1", "old +", "new * 2", "old 2"].map(Expression::parse);
+
+        // Then
+        assert!(result.iter().all(|expression| expression.is_err()));
+    }
+}
+
+mod evaluate {
+    use day_11_monkeys::Expression;
+
+    #[test]
+    fn test_parentheses_and_subtraction() {
+        // Given
+        let expression = Expression::parse("(old - 2) * (old + 3) - 1").unwrap();
+
+        // When
+        let result = expression.evaluate(10);
+
+        // Then
+        assert_eq!(result, 8 * 13 - 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_negative_worry() {
+        // Given
+        let expression = Expression::parse("old - 5").unwrap();
+
+        // When
+        expression.evaluate(3);
+    }
+}
+
+mod evaluate_modulo {
+    use day_11_monkeys::Expression;
+
+    #[test]
+    fn test_matches_plain_evaluation() {
+        // Given
+        let expression = Expression::parse("old * old + 3 - (old + 7) * 2").unwrap();
+
+        // When
+        let result: Vec<(u64, u64)> = (20..40)
+            .map(|old| {
+                (
+                    expression.evaluate_modulo(old % 13, 13),
+                    expression.evaluate(old) % 13,
+                )
+            })
+            .collect();
+
+        // Then
+        assert!(result.iter().all(|(modulo, plain)| modulo == plain));
+    }
+}
+
+mod operation_parse {
+    use day_11_monkeys::{Item, Operation, Worry};
+
+    #[test]
+    fn test_manipulate() {
+        // Given
+        let operation = Operation::parse("new = old * old + 3").unwrap();
+        let mut item = Item::new(4);
+
+        // When
+        operation.manipulate(&mut item, None);
+
+        // Then
+        assert_eq!(item.worry, Worry::Level(19));
+    }
+
+    #[test]
+    fn test_missing_prefix() {
+        // When
+        let result = Operation::parse("old * old");
+
+        // Then
+        assert!(result.is_err());
+    }
+}
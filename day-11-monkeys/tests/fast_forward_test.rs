@@ -0,0 +1,84 @@
+mod common;
+
+mod fast_forward {
+    use crate::common::build_crew;
+    use day_11_monkeys::{InspectionCount, Relief, INTEREST_LOSS_FACTOR};
+
+    #[test]
+    fn test_matches_simulation() {
+        // Given
+        let mut crew = build_crew(Relief::Residues);
+        let mut simulated_crew = build_crew(Relief::Residues);
+        simulated_crew.do_rounds(10000);
+
+        // When
+        let result = crew.fast_forward(10000);
+
+        // Then
+        let expected: Vec<InspectionCount> = simulated_crew
+            .get_manipulations_counts()
+            .iter()
+            .map(|count| *count as InspectionCount)
+            .collect();
+        assert_eq!(result, expected);
+        assert_eq!(crew.get_round_number(), 0);
+    }
+
+    #[test]
+    fn test_divide_relief() {
+        // Given
+        let mut crew = build_crew(Relief::DivideBy(INTEREST_LOSS_FACTOR));
+
+        // When
+        let result = crew.fast_forward(20);
+
+        // Then
+        assert_eq!(result, vec![101, 95, 7, 105]);
+    }
+
+    #[test]
+    fn test_after_simulated_rounds() {
+        // Given
+        let mut crew = build_crew(Relief::ModuloLcm);
+        crew.do_rounds(1000);
+
+        // When
+        let result = crew.fast_forward(9000);
+
+        // Then
+        assert_eq!(result, vec![52166, 47830, 1938, 52013]);
+    }
+}
+
+mod get_monkey_business_after {
+    use crate::common::build_crew;
+    use day_11_monkeys::Relief;
+
+    #[test]
+    fn test_puzzle_rounds() {
+        // Given
+        let mut crew = build_crew(Relief::ModuloLcm);
+
+        // When
+        let result = crew.get_monkey_business_after(10000, 2);
+
+        // Then
+        assert_eq!(result, 2713310158);
+    }
+
+    #[test]
+    fn test_huge_number_of_rounds() {
+        // Given
+        let mut crew = build_crew(Relief::Residues);
+
+        // When
+        let counts = crew.fast_forward(1_000_000_000_000);
+        let result = crew.get_monkey_business_after(1_000_000_000_000, 2);
+
+        // Then
+        assert!(counts.iter().all(|count| *count > 1_000_000_000));
+        let mut sorted_counts = counts.clone();
+        sorted_counts.sort_unstable();
+        assert_eq!(result, sorted_counts[2] * sorted_counts[3]);
+    }
+}
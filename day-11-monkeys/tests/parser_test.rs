@@ -0,0 +1,120 @@
+mod common;
+
+mod parse_monkeys {
+    use crate::common::EXAMPLE;
+    use day_11_monkeys::{parse_monkeys, ParseError};
+
+    #[test]
+    fn test_example() {
+        // When
+        let result = parse_monkeys(EXAMPLE).unwrap();
+
+        // Then
+        assert_eq!(result.len(), 4);
+        assert_eq!(result[2].id, 2);
+        assert_eq!(result[2].items.len(), 3);
+        assert_eq!(result[2].test_value, 13);
+        assert_eq!((result[2].target_true, result[2].target_false), (1, 3));
+    }
+
+    #[test]
+    fn test_no_starting_items() {
+        // Given
+        let text = EXAMPLE.replace("Starting items: 74", "Starting items:");
+
+        // When
+        let result = parse_monkeys(&text).unwrap();
+
+        // Then
+        assert!(result[3].items.is_empty());
+    }
+
+    #[test]
+    fn test_missing_line() {
+        // Given
+        let text = EXAMPLE.replace("  Test: divisible by 19\n", "");
+
+        // When
+        let result = parse_monkeys(&text).unwrap_err();
+
+        // Then
+        assert_eq!(
+            result,
+            vec![ParseError {
+                block_idx: 1,
+                line_number: Some(8),
+                message: String::from("Expected 6 lines but found 5"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_invalid_fields() {
+        // Given
+        let text = EXAMPLE
+            .replace("old + 6", "old / 6")
+            .replace("divisible by 17", "divisible by 0");
+
+        // When
+        let result = parse_monkeys(&text).unwrap_err();
+
+        // Then
+        assert_eq!(
+            result
+                .iter()
+                .map(|error| (error.block_idx, error.line_number))
+                .collect::<Vec<_>>(),
+            vec![(1, Some(10)), (3, Some(25))]
+        );
+        assert_eq!(
+            result[1].to_string(),
+            "Monkey block 3 (line 25): Test value must be positive"
+        );
+    }
+
+    #[test]
+    fn test_unknown_target() {
+        // Given
+        let text = EXAMPLE.replace("If false: throw to monkey 0", "If false: throw to monkey 4");
+
+        // When
+        let result = parse_monkeys(&text).unwrap_err();
+
+        // Then
+        assert_eq!(
+            result,
+            vec![ParseError {
+                block_idx: 1,
+                line_number: None,
+                message: String::from("Target monkey `4` does not exist"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_non_contiguous_ids() {
+        // Given
+        let text = EXAMPLE.replace("Monkey 2:", "Monkey 5:");
+
+        // When
+        let result = parse_monkeys(&text).unwrap_err();
+
+        // Then
+        assert_eq!(
+            result[0].to_string(),
+            "Monkey block 2: Expected monkey `2` but found monkey `5`"
+        );
+    }
+
+    #[test]
+    fn test_throw_to_itself() {
+        // Given
+        let text = EXAMPLE.replace("If true: throw to monkey 2", "If true: throw to monkey 0");
+
+        // When
+        let result = parse_monkeys(&text).unwrap_err();
+
+        // Then
+        assert_eq!(result[0].message, "Monkey `0` throws items to itself");
+    }
+}
@@ -0,0 +1,116 @@
+mod common;
+
+mod render_items {
+    use crate::common::build_crew;
+    use day_11_monkeys::{Relief, INTEREST_LOSS_FACTOR};
+
+    #[test]
+    fn test_first_round() {
+        // Given
+        let mut crew = build_crew(Relief::DivideBy(INTEREST_LOSS_FACTOR));
+        crew.enable_trace();
+
+        // When
+        crew.do_round();
+
+        // Then
+        assert_eq!(
+            crew.get_trace()[0].render_items(),
+            "After round 1, the monkeys are holding items with these worry levels:
+Monkey 0: 20, 23, 27, 26
+Monkey 1: 2080, 25, 167, 207, 401, 1046
+Monkey 2: 
+Monkey 3: "
+        );
+    }
+
+    #[test]
+    fn test_residues() {
+        // Given
+        let mut crew = build_crew(Relief::Residues);
+        crew.enable_trace();
+
+        // When
+        crew.do_round();
+
+        // Then
+        let monkey = &crew.get_trace()[0].monkeys[0];
+        assert_eq!(monkey.items[0].to_string(), "8%13|9%17|3%19|14%23");
+    }
+}
+
+mod render_inspections {
+    use crate::common::build_crew;
+    use day_11_monkeys::Relief;
+
+    #[test]
+    fn test_rounds() {
+        // Given
+        let mut crew = build_crew(Relief::Residues);
+        crew.enable_trace();
+
+        // When
+        crew.do_rounds(20);
+
+        // Then
+        let trace = crew.get_trace();
+        assert_eq!(trace.len(), 20);
+        assert_eq!(
+            trace[0].render_inspections(),
+            "== After round 1 ==
+Monkey 0 inspected items 2 times.
+Monkey 1 inspected items 4 times.
+Monkey 2 inspected items 3 times.
+Monkey 3 inspected items 6 times."
+        );
+        assert_eq!(
+            trace[19]
+                .monkeys
+                .iter()
+                .map(|monkey| monkey.manipulations_count)
+                .collect::<Vec<usize>>(),
+            vec![99, 97, 8, 103]
+        );
+    }
+}
+
+mod get_trace {
+    use crate::common::build_crew;
+    use day_11_monkeys::Relief;
+
+    #[test]
+    fn test_disabled_by_default() {
+        // Given
+        let mut crew = build_crew(Relief::ModuloLcm);
+
+        // When
+        crew.do_rounds(3);
+
+        // Then
+        assert!(crew.get_trace().is_empty());
+        assert_eq!(crew.get_round_number(), 3);
+    }
+}
+
+mod to_csv {
+    use crate::common::build_crew;
+    use day_11_monkeys::{to_csv, Relief};
+
+    #[test]
+    fn test_time_series() {
+        // Given
+        let mut crew = build_crew(Relief::ModuloLcm);
+        crew.enable_trace();
+        crew.do_rounds(2);
+
+        // When
+        let result = to_csv(crew.get_trace());
+
+        // Then
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines.len(), 1 + 2 * 4);
+        assert_eq!(lines[0], "round,monkey,items_held,inspections");
+        assert_eq!(lines[1], "1,0,4,2");
+        assert_eq!(lines[8], "2,3,0,10");
+    }
+}
@@ -1,229 +1,11 @@
-#![feature(map_first_last)]
-
-use std::collections::HashMap;
 use std::env;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{self, BufRead};
 use std::path::Path;
 
-const MAX_ADJACENTS: usize = 4;
-type Height = u16;
-type Id = usize;
-type Distance = usize;
-
-struct Pos {
-    x: usize,
-    y: usize,
-}
-
-impl Pos {
-    fn get_id(&self, width: usize) -> Id {
-        self.x + self.y * width
-    }
-}
-
-struct PathFinding<'a> {
-    graph: &'a Graph,
-    initial_pos: Id,
-    mapping: HashMap<Id, Distance>,
-}
-
-impl<'a> PathFinding<'a> {
-    fn new(graph: &'a Graph, initial_pos: Id) -> Self {
-        let mapping = HashMap::new();
-        PathFinding {
-            graph,
-            initial_pos,
-            mapping,
-        }
-    }
-
-    fn find_paths(&mut self) {
-        self.find_paths_rec(self.initial_pos, 0);
-    }
-
-    fn find_paths_rec(&mut self, explored_id: Id, distance: Distance) {
-        let current_distance = self.mapping.entry(explored_id).or_insert(distance);
-        if *current_distance > distance {
-            *current_distance = distance;
-        }
-        self.graph
-            .nodes
-            .get(explored_id)
-            .unwrap()
-            .adjacents
-            .iter()
-            .for_each(|adjacent_id| match self.mapping.get(adjacent_id) {
-                Some(adjacent_distance) => {
-                    if *adjacent_distance > distance + 1 {
-                        self.find_paths_rec(*adjacent_id, distance + 1);
-                    }
-                }
-                None => self.find_paths_rec(*adjacent_id, distance + 1),
-            });
-    }
-}
-
-struct Graph {
-    nodes: Vec<Node>,
-}
-
-impl Graph {
-    fn from_map(map: &Map) -> Self {
-        let width = map.get_width();
-        let height = map.get_height();
-        let mut nodes = Vec::with_capacity(width * height);
+use day_12_hills::{render_path, Graph, Height, MapFactory, PathFinding};
 
-        map.rows.iter().enumerate().for_each(|(y, row)| {
-            row.iter().enumerate().for_each(|(x, _)| {
-                let pos = Pos { x, y };
-
-                let height = map.get_tile_height(&pos);
-                let adjacent_positions = map.get_accessible_tiles_from_pos(&pos);
-                let adjacents = adjacent_positions
-                    .iter()
-                    .map(|adjacent_pos| adjacent_pos.get_id(width))
-                    .collect();
-
-                nodes.push(Node { adjacents, height });
-            });
-        });
-        Graph { nodes }
-    }
-}
-
-struct Node {
-    adjacents: Vec<Id>,
-    height: Height,
-}
-
-fn can_go(from: Height, to: Height) -> bool {
-    from + 1 >= to
-}
-
-struct Map {
-    end_pos: Pos,
-    rows: Vec<Vec<Height>>,
-}
-
-impl Map {
-    fn get_accessible_tiles_from_pos(&self, pos: &Pos) -> Vec<Pos> {
-        let vec = self.get_adjacent_tiles(pos);
-        let current_height = self.get_tile_height(pos);
-        vec.into_iter()
-            .filter(|adjacent_pos| {
-                let adjacent_height = self.get_tile_height(adjacent_pos);
-                can_go(adjacent_height, current_height)
-            })
-            .collect()
-    }
-
-    fn get_adjacent_tiles(&self, pos: &Pos) -> Vec<Pos> {
-        let mut vec = Vec::with_capacity(MAX_ADJACENTS);
-        if pos.x > 0 {
-            vec.push(Pos {
-                x: pos.x - 1,
-                y: pos.y,
-            });
-        }
-        let width = self.get_width();
-        if pos.x + 1 < width {
-            vec.push(Pos {
-                x: pos.x + 1,
-                y: pos.y,
-            });
-        }
-
-        if pos.y > 0 {
-            vec.push(Pos {
-                x: pos.x,
-                y: pos.y - 1,
-            });
-        }
-        let height = self.get_height();
-        if pos.y + 1 < height {
-            vec.push(Pos {
-                x: pos.x,
-                y: pos.y + 1,
-            });
-        }
-        vec
-    }
-
-    fn get_width(&self) -> usize {
-        self.rows.first().unwrap().len()
-    }
-
-    fn get_height(&self) -> usize {
-        self.rows.len()
-    }
-
-    fn get_tile_height(&self, pos: &Pos) -> Height {
-        *self.rows.get(pos.y).unwrap().get(pos.x).unwrap()
-    }
-}
-
-struct MapFactory {
-    end_pos: Option<Pos>,
-    rows: Vec<Vec<Height>>,
-    width: Option<usize>,
-}
-
-fn char_to_height(c: char) -> Height {
-    c as Height - 'a' as Height
-}
-
-impl MapFactory {
-    fn new() -> Self {
-        MapFactory {
-            end_pos: None,
-            rows: Vec::new(),
-            width: None,
-        }
-    }
-
-    fn parse_line(&mut self, line: &str) {
-        let row: Vec<Height> = line
-            .chars()
-            .enumerate()
-            .map(|(x, c)| {
-                let letter = match c {
-                    'S' => 'a',
-                    'E' => {
-                        if self.end_pos.is_some() {
-                            panic!("There is already a end position");
-                        }
-                        let y = self.rows.len();
-                        self.end_pos = Some(Pos { x, y });
-                        'z'
-                    }
-                    _ => c,
-                };
-                char_to_height(letter)
-            })
-            .collect();
-        let width = row.len();
-        match self.width {
-            Some(current_width) => assert_eq!(
-                width, current_width,
-                "The provided line width {} is different than the map width {}",
-                width, current_width
-            ),
-            None => self.width = Some(width),
-        }
-        self.rows.push(row);
-    }
-
-    fn create_map(self) -> Map {
-        let MapFactory {
-            end_pos,
-            rows,
-            width: _width,
-        } = self;
-        let end_pos = end_pos.unwrap();
-        Map { end_pos, rows }
-    }
-}
+const LOWEST_HEIGHT: Height = 0;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
@@ -250,20 +32,30 @@ fn main() {
     println!("Create graph");
     let graph = Graph::from_map(&map);
 
-    let width = map.get_width();
-    let end_id = map.end_pos.get_id(width);
+    let end_id = graph.get_id(&map.get_end_pos().unwrap());
 
     println!("Find Paths");
-    let mut path_finding = PathFinding::new(&graph, end_id);
-    path_finding.find_paths();
-
-    let result = graph
-        .nodes
-        .iter()
-        .enumerate()
-        .filter(|(id, node)| node.height == 0 && path_finding.mapping.contains_key(id))
-        .map(|(id, _)| path_finding.mapping.get(&id).unwrap())
-        .fold(Distance::MAX, |a, b| a.min(*b));
-
+    let start_ids = graph.get_ids_at_height(LOWEST_HEIGHT);
+    let mut path_finding = PathFinding::from_sources(&graph, start_ids);
+    let result = path_finding.bfs_to(end_id).unwrap();
     println!("Result is `{}`", result);
+
+    if let Some(format) = args.get(2) {
+        let export = match format.as_str() {
+            "path" => render_path(&map, &path_finding.get_path_to(end_id).unwrap()),
+            "heatmap" => {
+                path_finding.bfs();
+                path_finding.to_heatmap_pgm()
+            }
+            "csv" => {
+                path_finding.bfs();
+                path_finding.to_distances_csv()
+            }
+            _ => panic!("Invalid export format: `{}`", format),
+        };
+        match args.get(3) {
+            Some(output_path) => fs::write(output_path, export).unwrap(),
+            None => println!("{}", export),
+        }
+    }
 }
@@ -0,0 +1,60 @@
+use crate::path_finding::{Distance, PathFinding};
+
+const MAX_GRAY_VALUE: Distance = 255;
+const UNREACHED_VALUE: Distance = 0;
+
+impl PathFinding<'_> {
+    // Sources are white and the farthest tiles are the darkest gray, tiles
+    // which were not reached stay black
+    pub fn to_heatmap_pgm(&self) -> String {
+        let max_distance = self.get_distances().iter().flatten().max().copied();
+        let max_distance = max_distance.unwrap_or(0).max(1);
+        let values: Vec<Distance> = self
+            .get_distances()
+            .iter()
+            .map(|distance| match distance {
+                Some(distance) => MAX_GRAY_VALUE - distance * (MAX_GRAY_VALUE - 1) / max_distance,
+                None => UNREACHED_VALUE,
+            })
+            .collect();
+        let width = self.graph.get_width();
+        let height = match width {
+            0 => 0,
+            _ => values.len() / width,
+        };
+        let pixels: Vec<String> = values
+            .chunks(width.max(1))
+            .map(|row| {
+                row.iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect();
+        format!(
+            "P2\n{} {}\n{}\n{}\n",
+            width,
+            height,
+            MAX_GRAY_VALUE,
+            pixels.join("\n")
+        )
+    }
+
+    pub fn to_distances_csv(&self) -> String {
+        let width = self.graph.get_width().max(1);
+        let lines: Vec<String> = self
+            .get_distances()
+            .chunks(width)
+            .map(|row| {
+                row.iter()
+                    .map(|distance| match distance {
+                        Some(distance) => distance.to_string(),
+                        None => String::new(),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(",")
+            })
+            .collect();
+        format!("{}\n", lines.join("\n"))
+    }
+}
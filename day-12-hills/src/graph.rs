@@ -0,0 +1,95 @@
+use grid::Pos;
+
+use crate::map::{Height, Map, MovementRules};
+
+pub type Id = usize;
+
+pub fn get_id(pos: &Pos, width: usize) -> Id {
+    pos.x + pos.y * width
+}
+
+pub fn get_pos(id: Id, width: usize) -> Pos {
+    Pos {
+        x: id % width,
+        y: id / width,
+    }
+}
+
+pub struct Node {
+    pub adjacents: Vec<Id>,
+    pub height: Height,
+}
+
+pub struct Graph {
+    pub nodes: Vec<Node>,
+    width: usize,
+    rules: MovementRules,
+}
+
+impl Graph {
+    pub fn from_map(map: &Map) -> Self {
+        Self::with_rules(map, &MovementRules::default())
+    }
+
+    pub fn with_rules(map: &Map, rules: &MovementRules) -> Self {
+        let width = map.get_width();
+        let height = map.get_height();
+        let mut nodes = Vec::with_capacity(width * height);
+
+        map.rows.positions().for_each(|pos| {
+            let adjacent_positions = map.get_accessible_tiles_from_pos(&pos, rules);
+            let adjacents = adjacent_positions
+                .iter()
+                .map(|adjacent_pos| get_id(adjacent_pos, width))
+                .collect();
+            let height = map.get_tile_height(&pos);
+
+            nodes.push(Node { adjacents, height });
+        });
+        Graph {
+            nodes,
+            width,
+            rules: *rules,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_rules(&self) -> &MovementRules {
+        &self.rules
+    }
+
+    pub fn get_node(&self, id: Id) -> &Node {
+        match self.nodes.get(id) {
+            Some(node) => node,
+            None => panic!("Unknown node: `{}`", id),
+        }
+    }
+
+    pub fn get_id(&self, pos: &Pos) -> Id {
+        get_id(pos, self.width)
+    }
+
+    pub fn get_pos(&self, id: Id) -> Pos {
+        get_pos(id, self.width)
+    }
+
+    pub fn get_ids_at_height(&self, height: Height) -> Vec<Id> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.height == height)
+            .map(|(id, _)| id)
+            .collect()
+    }
+}
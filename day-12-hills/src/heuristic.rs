@@ -0,0 +1,65 @@
+use crate::graph::{Graph, Id};
+use crate::map::Height;
+use crate::path_finding::Distance;
+
+// Every estimate is a lower bound of the number of steps left, so A* still
+// finds the shortest path
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Heuristic {
+    Zero,
+    Manhattan,
+    HeightDifference,
+    Combined,
+}
+
+impl Heuristic {
+    pub fn parse(word: &str) -> Self {
+        match word {
+            "zero" => Heuristic::Zero,
+            "manhattan" => Heuristic::Manhattan,
+            "height" => Heuristic::HeightDifference,
+            "combined" => Heuristic::Combined,
+            _ => panic!("Invalid heuristic: `{}`", word),
+        }
+    }
+
+    pub fn estimate(&self, graph: &Graph, from: Id, to: Id) -> Distance {
+        match self {
+            Heuristic::Zero => 0,
+            Heuristic::Manhattan => get_grid_distance(graph, from, to),
+            Heuristic::HeightDifference => get_height_distance(graph, from, to),
+            Heuristic::Combined => {
+                get_grid_distance(graph, from, to).max(get_height_distance(graph, from, to))
+            }
+        }
+    }
+}
+
+// With diagonal moves a single step covers both axes at once
+fn get_grid_distance(graph: &Graph, from: Id, to: Id) -> Distance {
+    let from = graph.get_pos(from);
+    let to = graph.get_pos(to);
+    let delta_x = from.x.abs_diff(to.x);
+    let delta_y = from.y.abs_diff(to.y);
+    match graph.get_rules().diagonal_moves {
+        true => delta_x.max(delta_y),
+        false => delta_x + delta_y,
+    }
+}
+
+fn get_height_distance(graph: &Graph, from: Id, to: Id) -> Distance {
+    let from = graph.get_node(from).height;
+    let to = graph.get_node(to).height;
+    let rules = graph.get_rules();
+    match to >= from {
+        true => get_nb_steps(to - from, rules.max_climb),
+        false => get_nb_steps(from - to, rules.max_descent),
+    }
+}
+
+fn get_nb_steps(difference: Height, max_per_step: Option<Height>) -> Distance {
+    match max_per_step {
+        Some(max) if max > 0 => difference.div_ceil(max) as Distance,
+        _ => 0,
+    }
+}
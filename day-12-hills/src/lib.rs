@@ -0,0 +1,15 @@
+mod export;
+mod graph;
+mod heuristic;
+mod map;
+mod path_finding;
+mod render;
+
+pub use graph::{get_id, get_pos, Graph, Id, Node};
+pub use heuristic::Heuristic;
+pub use map::{
+    char_to_height, Height, HeightmapFormat, Map, MapFactory, MovementRules, END_MARKER,
+    START_MARKER,
+};
+pub use path_finding::{Distance, PathFinding};
+pub use render::{get_arrow, get_direction, render_path};
@@ -0,0 +1,212 @@
+use grid::{Grid, Pos};
+
+pub type Height = u16;
+
+pub const START_MARKER: char = 'S';
+pub const END_MARKER: char = 'E';
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HeightmapFormat {
+    Letters,
+    Digits,
+}
+
+impl HeightmapFormat {
+    pub fn parse(word: &str) -> Self {
+        match word {
+            "letters" => HeightmapFormat::Letters,
+            "digits" => HeightmapFormat::Digits,
+            _ => panic!("Invalid heightmap format: `{}`", word),
+        }
+    }
+
+    pub fn get_lowest(&self) -> Height {
+        0
+    }
+
+    pub fn get_highest(&self) -> Height {
+        match self {
+            HeightmapFormat::Letters => char_to_height('z'),
+            HeightmapFormat::Digits => 9,
+        }
+    }
+
+    pub fn get_height(&self, c: char) -> Height {
+        match (self, c) {
+            (HeightmapFormat::Letters, 'a'..='z') => char_to_height(c),
+            (HeightmapFormat::Digits, '0'..='9') => c.to_digit(10).unwrap() as Height,
+            _ => panic!("Invalid height: `{}`", c),
+        }
+    }
+}
+
+// A `None` limit allows any difference of height in that direction
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MovementRules {
+    pub max_climb: Option<Height>,
+    pub max_descent: Option<Height>,
+    pub diagonal_moves: bool,
+}
+
+impl Default for MovementRules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MovementRules {
+    pub fn new() -> Self {
+        MovementRules {
+            max_climb: Some(1),
+            max_descent: None,
+            diagonal_moves: false,
+        }
+    }
+
+    // Rules for walking the same paths backward, from the end to the start
+    pub fn reversed(&self) -> Self {
+        MovementRules {
+            max_climb: self.max_descent,
+            max_descent: self.max_climb,
+            diagonal_moves: self.diagonal_moves,
+        }
+    }
+
+    pub fn can_go(&self, from: Height, to: Height) -> bool {
+        match to >= from {
+            true => self.max_climb.is_none_or(|max| to - from <= max),
+            false => self.max_descent.is_none_or(|max| from - to <= max),
+        }
+    }
+}
+
+pub struct Map {
+    start_pos: Option<Pos>,
+    end_pos: Option<Pos>,
+    pub rows: Grid<Height>,
+}
+
+impl Map {
+    pub fn get_start_pos(&self) -> Option<Pos> {
+        self.start_pos
+    }
+
+    pub fn get_end_pos(&self) -> Option<Pos> {
+        self.end_pos
+    }
+
+    pub fn get_accessible_tiles_from_pos(&self, pos: &Pos, rules: &MovementRules) -> Vec<Pos> {
+        let vec = self.get_adjacent_tiles(pos, rules.diagonal_moves);
+        let current_height = self.get_tile_height(pos);
+        vec.into_iter()
+            .filter(|adjacent_pos| {
+                let adjacent_height = self.get_tile_height(adjacent_pos);
+                rules.can_go(current_height, adjacent_height)
+            })
+            .collect()
+    }
+
+    pub fn get_adjacent_tiles(&self, pos: &Pos, diagonal_moves: bool) -> Vec<Pos> {
+        match diagonal_moves {
+            true => self.rows.get_neighbours_8(pos).collect(),
+            false => self.rows.get_neighbours_4(pos).collect(),
+        }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.rows.get_width()
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.rows.get_height()
+    }
+
+    pub fn get_tile_height(&self, pos: &Pos) -> Height {
+        self.rows[pos]
+    }
+}
+
+pub struct MapFactory {
+    format: HeightmapFormat,
+    start_pos: Option<Pos>,
+    end_pos: Option<Pos>,
+    rows: Grid<Height>,
+}
+
+pub fn char_to_height(c: char) -> Height {
+    c as Height - 'a' as Height
+}
+
+impl Default for MapFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MapFactory {
+    pub fn new() -> Self {
+        Self::with_format(HeightmapFormat::Letters)
+    }
+
+    pub fn with_format(format: HeightmapFormat) -> Self {
+        MapFactory {
+            format,
+            start_pos: None,
+            end_pos: None,
+            rows: Grid::default(),
+        }
+    }
+
+    // `S` and `E` markers stand for the lowest and the highest tiles
+    pub fn parse_line(&mut self, line: &str) {
+        let y = self.rows.get_height();
+        let row: Vec<Height> = line
+            .chars()
+            .enumerate()
+            .map(|(x, c)| match c {
+                START_MARKER => {
+                    self.set_start_pos(Pos { x, y });
+                    self.format.get_lowest()
+                }
+                END_MARKER => {
+                    self.set_end_pos(Pos { x, y });
+                    self.format.get_highest()
+                }
+                _ => self.format.get_height(c),
+            })
+            .collect();
+        self.rows.push_row(row);
+    }
+
+    pub fn set_start_pos(&mut self, pos: Pos) {
+        if self.start_pos.is_some() {
+            panic!("There is already a start position");
+        }
+        self.start_pos = Some(pos);
+    }
+
+    pub fn set_end_pos(&mut self, pos: Pos) {
+        if self.end_pos.is_some() {
+            panic!("There is already a end position");
+        }
+        self.end_pos = Some(pos);
+    }
+
+    pub fn create_map(self) -> Map {
+        let MapFactory {
+            start_pos,
+            end_pos,
+            rows,
+            ..
+        } = self;
+        let is_outside = |pos: &Option<Pos>| pos.is_some_and(|pos| !rows.contains(&pos));
+        if is_outside(&start_pos) || is_outside(&end_pos) {
+            panic!("The start and end positions must be inside the map");
+        }
+        Map {
+            start_pos,
+            end_pos,
+            rows,
+        }
+    }
+}
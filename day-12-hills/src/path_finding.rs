@@ -0,0 +1,201 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use grid::Pos;
+
+use crate::graph::{Graph, Id, Node};
+use crate::heuristic::Heuristic;
+
+pub type Distance = usize;
+
+pub struct PathFinding<'a> {
+    pub(crate) graph: &'a Graph,
+    sources: Vec<Id>,
+    distances: Vec<Option<Distance>>,
+    predecessors: Vec<Option<Id>>,
+    nb_expanded: usize,
+}
+
+impl<'a> PathFinding<'a> {
+    pub fn new(graph: &'a Graph, source: Id) -> Self {
+        Self::from_sources(graph, vec![source])
+    }
+
+    // Every source starts at distance 0, so a single search yields the
+    // shortest distance from the closest one
+    pub fn from_sources(graph: &'a Graph, sources: Vec<Id>) -> Self {
+        if let Some(id) = sources.iter().find(|id| **id >= graph.len()) {
+            panic!("Unknown source node: `{}`", id);
+        }
+        PathFinding {
+            graph,
+            sources,
+            distances: vec![None; graph.len()],
+            predecessors: vec![None; graph.len()],
+            nb_expanded: 0,
+        }
+    }
+
+    pub fn bfs(&mut self) {
+        self.run_bfs(None);
+    }
+
+    pub fn bfs_to(&mut self, target: Id) -> Option<Distance> {
+        self.run_bfs(Some(target))
+    }
+
+    pub fn dijkstra<F>(&mut self, cost: F)
+    where
+        F: Fn(&Node, &Node) -> Distance,
+    {
+        self.run_dijkstra(None, cost);
+    }
+
+    pub fn dijkstra_to<F>(&mut self, target: Id, cost: F) -> Option<Distance>
+    where
+        F: Fn(&Node, &Node) -> Distance,
+    {
+        self.run_dijkstra(Some(target), cost)
+    }
+
+    pub fn astar_to(&mut self, target: Id, heuristic: Heuristic) -> Option<Distance> {
+        self.reset();
+        let graph = self.graph;
+        let estimate = |id: Id| heuristic.estimate(graph, id, target);
+        let mut heap: BinaryHeap<Reverse<(Distance, Distance, Id)>> = self
+            .sources
+            .iter()
+            .map(|id| Reverse((estimate(*id), 0, *id)))
+            .collect();
+
+        while let Some(Reverse((_, distance, id))) = heap.pop() {
+            if self.distances[id].is_some_and(|known| known < distance) {
+                continue;
+            }
+            self.nb_expanded += 1;
+            if id == target {
+                return Some(distance);
+            }
+            graph.get_node(id).adjacents.iter().for_each(|adjacent_id| {
+                let adjacent_distance = distance + 1;
+                let is_shorter = match self.distances[*adjacent_id] {
+                    Some(known) => adjacent_distance < known,
+                    None => true,
+                };
+                if is_shorter {
+                    self.distances[*adjacent_id] = Some(adjacent_distance);
+                    self.predecessors[*adjacent_id] = Some(id);
+                    let priority = adjacent_distance + estimate(*adjacent_id);
+                    heap.push(Reverse((priority, adjacent_distance, *adjacent_id)));
+                }
+            });
+        }
+        None
+    }
+
+    // Number of nodes taken out of the frontier during the last search
+    pub fn get_nb_expanded(&self) -> usize {
+        self.nb_expanded
+    }
+
+    pub fn get_distance(&self, id: Id) -> Option<Distance> {
+        self.distances.get(id).copied().flatten()
+    }
+
+    pub fn get_distances(&self) -> &[Option<Distance>] {
+        &self.distances
+    }
+
+    pub fn get_predecessor(&self, id: Id) -> Option<Id> {
+        self.predecessors.get(id).copied().flatten()
+    }
+
+    pub fn is_reached(&self, id: Id) -> bool {
+        self.get_distance(id).is_some()
+    }
+
+    // Positions from the closest source to the target, both included
+    pub fn get_path_to(&self, target: Id) -> Option<Vec<Pos>> {
+        if !self.is_reached(target) {
+            return None;
+        }
+        let mut path = vec![self.graph.get_pos(target)];
+        let mut id = target;
+        while let Some(predecessor) = self.get_predecessor(id) {
+            path.push(self.graph.get_pos(predecessor));
+            id = predecessor;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn find_path(&mut self, target: Id) -> Option<Vec<Pos>> {
+        self.bfs_to(target)?;
+        self.get_path_to(target)
+    }
+
+    fn reset(&mut self) {
+        self.distances.iter_mut().for_each(|d| *d = None);
+        self.predecessors.iter_mut().for_each(|p| *p = None);
+        self.nb_expanded = 0;
+        let sources = &self.sources;
+        sources.iter().for_each(|id| self.distances[*id] = Some(0));
+    }
+
+    fn run_bfs(&mut self, target: Option<Id>) -> Option<Distance> {
+        self.reset();
+        let graph = self.graph;
+        let mut queue: VecDeque<Id> = self.sources.iter().copied().collect();
+
+        while let Some(id) = queue.pop_front() {
+            let distance = self.distances[id].unwrap();
+            self.nb_expanded += 1;
+            if target == Some(id) {
+                return Some(distance);
+            }
+            graph.get_node(id).adjacents.iter().for_each(|adjacent_id| {
+                if self.distances[*adjacent_id].is_none() {
+                    self.distances[*adjacent_id] = Some(distance + 1);
+                    self.predecessors[*adjacent_id] = Some(id);
+                    queue.push_back(*adjacent_id);
+                }
+            });
+        }
+        None
+    }
+
+    fn run_dijkstra<F>(&mut self, target: Option<Id>, cost: F) -> Option<Distance>
+    where
+        F: Fn(&Node, &Node) -> Distance,
+    {
+        self.reset();
+        let graph = self.graph;
+        let mut heap: BinaryHeap<Reverse<(Distance, Id)>> =
+            self.sources.iter().map(|id| Reverse((0, *id))).collect();
+
+        while let Some(Reverse((distance, id))) = heap.pop() {
+            // Stale entry, a shorter distance was found since it was pushed
+            if self.distances[id].is_some_and(|known| known < distance) {
+                continue;
+            }
+            self.nb_expanded += 1;
+            if target == Some(id) {
+                return Some(distance);
+            }
+            let node = graph.get_node(id);
+            node.adjacents.iter().for_each(|adjacent_id| {
+                let adjacent_distance = distance + cost(node, graph.get_node(*adjacent_id));
+                let is_shorter = match self.distances[*adjacent_id] {
+                    Some(known) => adjacent_distance < known,
+                    None => true,
+                };
+                if is_shorter {
+                    self.distances[*adjacent_id] = Some(adjacent_distance);
+                    self.predecessors[*adjacent_id] = Some(id);
+                    heap.push(Reverse((adjacent_distance, *adjacent_id)));
+                }
+            });
+        }
+        None
+    }
+}
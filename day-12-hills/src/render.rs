@@ -0,0 +1,44 @@
+use grid::{Direction, Grid, Pos};
+
+use crate::map::Map;
+
+const EMPTY_CELL: char = '.';
+const END_CELL: char = 'E';
+
+pub fn get_arrow(direction: Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+        Direction::Right => '>',
+        Direction::UpLeft | Direction::DownRight => '\\',
+        Direction::UpRight | Direction::DownLeft => '/',
+    }
+}
+
+pub fn get_direction(from: &Pos, to: &Pos) -> Direction {
+    match Direction::ALL
+        .into_iter()
+        .find(|direction| from.step(*direction).as_ref() == Some(to))
+    {
+        Some(direction) => direction,
+        None => panic!(
+            "Path positions are not adjacent: `{:?}` and `{:?}`",
+            from, to
+        ),
+    }
+}
+
+// Same layout as the puzzle's illustration: every step of the path shows
+// the direction taken to leave it, and the last position is marked `E`
+pub fn render_path(map: &Map, path: &[Pos]) -> String {
+    let mut cells = Grid::new(map.get_width(), map.get_height(), EMPTY_CELL);
+    path.windows(2).for_each(|step| {
+        let direction = get_direction(&step[0], &step[1]);
+        cells.set(&step[0], get_arrow(direction));
+    });
+    if let Some(last_pos) = path.last() {
+        cells.set(last_pos, END_CELL);
+    }
+    cells.to_string()
+}
@@ -0,0 +1,32 @@
+// Shared by several test crates, each of them only uses part of it
+#![allow(dead_code)]
+
+use day_12_hills::{Graph, HeightmapFormat, Map, MapFactory, MovementRules};
+
+pub const EXAMPLE: [&str; 5] = ["Sabqponm", "abcryxxl", "accszExk", "acctuvwj", "abdefghi"];
+
+pub fn build_map() -> Map {
+    parse_map(&EXAMPLE)
+}
+
+pub fn parse_map(lines: &[&str]) -> Map {
+    parse_map_as(HeightmapFormat::Letters, lines)
+}
+
+pub fn parse_map_as(format: HeightmapFormat, lines: &[&str]) -> Map {
+    let mut map_factory = MapFactory::with_format(format);
+    lines.iter().for_each(|line| map_factory.parse_line(line));
+    map_factory.create_map()
+}
+
+pub fn build_graph(lines: &[&str]) -> Graph {
+    Graph::from_map(&parse_map(lines))
+}
+
+pub fn build_graph_with_rules(
+    format: HeightmapFormat,
+    lines: &[&str],
+    rules: MovementRules,
+) -> Graph {
+    Graph::with_rules(&parse_map_as(format, lines), &rules)
+}
@@ -0,0 +1,39 @@
+mod common;
+
+mod to_heatmap_pgm {
+    use crate::common::build_graph;
+    use day_12_hills::PathFinding;
+
+    #[test]
+    fn test_distance_field() {
+        // Given
+        let graph = build_graph(&["Sbc", "aEz"]);
+        let mut path_finding = PathFinding::new(&graph, 0);
+        path_finding.bfs();
+
+        // When
+        let result = path_finding.to_heatmap_pgm();
+
+        // Then
+        assert_eq!(result, "P2\n3 2\n255\n255 128 1\n128 0 0\n");
+    }
+}
+
+mod to_distances_csv {
+    use crate::common::build_graph;
+    use day_12_hills::PathFinding;
+
+    #[test]
+    fn test_distance_field() {
+        // Given
+        let graph = build_graph(&["Sbc", "aEz"]);
+        let mut path_finding = PathFinding::new(&graph, 0);
+        path_finding.bfs();
+
+        // When
+        let result = path_finding.to_distances_csv();
+
+        // Then
+        assert_eq!(result, "0,1,2\n1,,\n");
+    }
+}
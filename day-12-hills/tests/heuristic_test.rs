@@ -0,0 +1,125 @@
+mod common;
+
+mod estimate {
+    use crate::common::build_graph_with_rules;
+    use day_12_hills::{HeightmapFormat, Heuristic, MovementRules};
+
+    #[test]
+    fn test_manhattan() {
+        // Given
+        let graph = build_graph_with_rules(
+            HeightmapFormat::Digits,
+            &["0000", "0000", "0000"],
+            MovementRules::default(),
+        );
+
+        // When
+        let result = Heuristic::Manhattan.estimate(&graph, 0, 11);
+
+        // Then
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn test_manhattan_with_diagonal_moves() {
+        // Given
+        let rules = MovementRules {
+            diagonal_moves: true,
+            ..MovementRules::default()
+        };
+        let graph =
+            build_graph_with_rules(HeightmapFormat::Digits, &["0000", "0000", "0000"], rules);
+
+        // When
+        let result = Heuristic::Manhattan.estimate(&graph, 0, 11);
+
+        // Then
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn test_height_difference() {
+        // Given
+        let graph = build_graph_with_rules(
+            HeightmapFormat::Digits,
+            &["0009", "0000"],
+            MovementRules::default(),
+        );
+
+        // When
+        let result = [
+            Heuristic::HeightDifference.estimate(&graph, 0, 3),
+            Heuristic::HeightDifference.estimate(&graph, 3, 0),
+        ];
+
+        // Then
+        assert_eq!(result, [9, 0]);
+    }
+
+    #[test]
+    fn test_height_difference_with_limits() {
+        // Given
+        let rules = MovementRules {
+            max_climb: Some(2),
+            max_descent: Some(4),
+            diagonal_moves: false,
+        };
+        let graph = build_graph_with_rules(HeightmapFormat::Digits, &["0009", "0000"], rules);
+
+        // When
+        let result = [
+            Heuristic::HeightDifference.estimate(&graph, 0, 3),
+            Heuristic::HeightDifference.estimate(&graph, 3, 0),
+        ];
+
+        // Then
+        assert_eq!(result, [5, 3]);
+    }
+
+    #[test]
+    fn test_combined() {
+        // Given
+        let graph = build_graph_with_rules(
+            HeightmapFormat::Digits,
+            &["0009", "0000"],
+            MovementRules::default(),
+        );
+
+        // When
+        let result = [
+            Heuristic::Combined.estimate(&graph, 0, 3),
+            Heuristic::Combined.estimate(&graph, 3, 4),
+        ];
+
+        // Then
+        assert_eq!(result, [9, 4]);
+    }
+}
+
+mod parse {
+    use day_12_hills::Heuristic;
+
+    #[test]
+    fn test_valid() {
+        // When
+        let result = ["zero", "manhattan", "height", "combined"].map(Heuristic::parse);
+
+        // Then
+        assert_eq!(
+            result,
+            [
+                Heuristic::Zero,
+                Heuristic::Manhattan,
+                Heuristic::HeightDifference,
+                Heuristic::Combined
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid heuristic: `euclid`")]
+    fn test_invalid() {
+        // When
+        Heuristic::parse("euclid");
+    }
+}
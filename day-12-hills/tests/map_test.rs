@@ -0,0 +1,179 @@
+mod common;
+
+mod can_go {
+    use day_12_hills::MovementRules;
+
+    #[test]
+    fn test_default_rules() {
+        // Given
+        let rules = MovementRules::default();
+
+        // When
+        let result = [(3, 4), (3, 5), (9, 0)].map(|(from, to)| rules.can_go(from, to));
+
+        // Then
+        assert_eq!(result, [true, false, true]);
+    }
+
+    #[test]
+    fn test_limited_descent() {
+        // Given
+        let rules = MovementRules {
+            max_climb: None,
+            max_descent: Some(2),
+            diagonal_moves: false,
+        };
+
+        // When
+        let result = [(0, 9), (5, 3), (5, 2)].map(|(from, to)| rules.can_go(from, to));
+
+        // Then
+        assert_eq!(result, [true, true, false]);
+    }
+
+    #[test]
+    fn test_reversed() {
+        // Given
+        let rules = MovementRules::default().reversed();
+
+        // When
+        let result = [(4, 3), (5, 3), (0, 9)].map(|(from, to)| rules.can_go(from, to));
+
+        // Then
+        assert_eq!(result, [true, false, true]);
+    }
+}
+
+mod parse_line {
+    use crate::common::parse_map_as;
+    use day_12_hills::HeightmapFormat;
+    use grid::Pos;
+
+    #[test]
+    fn test_letters() {
+        // Given
+        let lines = ["Sbz", "aEy"];
+
+        // When
+        let map = parse_map_as(HeightmapFormat::Letters, &lines);
+
+        // Then
+        assert_eq!(map.rows.row(0), &[0, 1, 25]);
+        assert_eq!(map.rows.row(1), &[0, 25, 24]);
+        assert_eq!(map.get_start_pos(), Some(Pos::new(0, 0)));
+        assert_eq!(map.get_end_pos(), Some(Pos::new(1, 1)));
+    }
+
+    #[test]
+    fn test_digits() {
+        // Given
+        let lines = ["0123", "98E7"];
+
+        // When
+        let map = parse_map_as(HeightmapFormat::Digits, &lines);
+
+        // Then
+        assert_eq!(map.rows.row(0), &[0, 1, 2, 3]);
+        assert_eq!(map.rows.row(1), &[9, 8, 9, 7]);
+        assert_eq!(map.get_start_pos(), None);
+        assert_eq!(map.get_end_pos(), Some(Pos::new(2, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid height: `a`")]
+    fn test_letter_in_digits() {
+        // Given
+        let lines = ["01a"];
+
+        // When
+        parse_map_as(HeightmapFormat::Digits, &lines);
+    }
+
+    #[test]
+    #[should_panic(expected = "There is already a start position")]
+    fn test_two_starts() {
+        // Given
+        let lines = ["SabS"];
+
+        // When
+        parse_map_as(HeightmapFormat::Letters, &lines);
+    }
+}
+
+mod set_start_pos {
+    use day_12_hills::{HeightmapFormat, MapFactory};
+    use grid::Pos;
+
+    #[test]
+    fn test_explicit_positions() {
+        // Given
+        let mut map_factory = MapFactory::with_format(HeightmapFormat::Digits);
+        map_factory.parse_line("012");
+        map_factory.parse_line("543");
+
+        // When
+        map_factory.set_start_pos(Pos::new(0, 0));
+        map_factory.set_end_pos(Pos::new(0, 1));
+        let map = map_factory.create_map();
+
+        // Then
+        assert_eq!(map.get_start_pos(), Some(Pos::new(0, 0)));
+        assert_eq!(map.get_end_pos(), Some(Pos::new(0, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "The start and end positions must be inside the map")]
+    fn test_outside_of_map() {
+        // Given
+        let mut map_factory = MapFactory::with_format(HeightmapFormat::Digits);
+        map_factory.parse_line("012");
+
+        // When
+        map_factory.set_start_pos(Pos::new(3, 0));
+        map_factory.create_map();
+    }
+}
+
+mod with_rules {
+    use crate::common::parse_map_as;
+    use day_12_hills::{Graph, HeightmapFormat, MovementRules, PathFinding};
+
+    #[test]
+    fn test_diagonal_moves() {
+        // Given
+        let map = parse_map_as(HeightmapFormat::Digits, &["S50", "51E"]);
+        let rules = MovementRules {
+            diagonal_moves: true,
+            ..MovementRules::default()
+        };
+        let graph = Graph::with_rules(&map, &rules);
+        let mut path_finding = PathFinding::new(&graph, 0);
+
+        // When
+        let result = path_finding.bfs_to(graph.get_id(&map.get_end_pos().unwrap()));
+
+        // Then
+        assert_eq!(graph.get_node(0).adjacents, vec![4]);
+        assert_eq!(result, None);
+        assert_eq!(path_finding.get_distance(2), Some(2));
+    }
+
+    #[test]
+    fn test_numeric_trail() {
+        // Given
+        let map = parse_map_as(HeightmapFormat::Digits, &["S123", "7654", "89E9"]);
+        let rules = MovementRules {
+            max_climb: Some(1),
+            max_descent: Some(0),
+            diagonal_moves: false,
+        };
+        let graph = Graph::with_rules(&map, &rules);
+        let mut path_finding = PathFinding::new(&graph, 0);
+
+        // When
+        let result = path_finding.bfs_to(graph.get_id(&map.get_end_pos().unwrap()));
+
+        // Then
+        assert_eq!(result, Some(10));
+    }
+}
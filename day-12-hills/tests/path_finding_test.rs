@@ -0,0 +1,346 @@
+mod common;
+
+use day_12_hills::{Distance, Node};
+
+mod bfs_to {
+    use crate::common::{build_map, parse_map};
+    use crate::DETOUR;
+    use day_12_hills::{Graph, PathFinding};
+
+    #[test]
+    fn test_from_start() {
+        // Given
+        let map = build_map();
+        let graph = Graph::from_map(&map);
+        let mut path_finding =
+            PathFinding::new(&graph, graph.get_id(&map.get_start_pos().unwrap()));
+
+        // When
+        let result = path_finding.bfs_to(graph.get_id(&map.get_end_pos().unwrap()));
+
+        // Then
+        assert_eq!(result, Some(31));
+    }
+
+    #[test]
+    fn test_from_any_lowest_tile() {
+        // Given
+        let map = build_map();
+        let graph = Graph::from_map(&map);
+        let mut path_finding = PathFinding::from_sources(&graph, graph.get_ids_at_height(0));
+
+        // When
+        let result = path_finding.bfs_to(graph.get_id(&map.get_end_pos().unwrap()));
+
+        // Then
+        assert_eq!(result, Some(29));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        // Given
+        let map = parse_map(&DETOUR);
+        let graph = Graph::from_map(&map);
+        let mut path_finding =
+            PathFinding::new(&graph, graph.get_id(&map.get_start_pos().unwrap()));
+
+        // When
+        let result = path_finding.bfs_to(graph.get_id(&map.get_end_pos().unwrap()));
+
+        // Then
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_stops_at_target() {
+        // Given
+        let map = build_map();
+        let graph = Graph::from_map(&map);
+        let start_id = graph.get_id(&map.get_start_pos().unwrap());
+        let mut path_finding = PathFinding::new(&graph, start_id);
+
+        // When
+        let result = path_finding.bfs_to(start_id + 1);
+
+        // Then
+        assert_eq!(result, Some(1));
+        assert!(!path_finding.is_reached(graph.get_id(&map.get_end_pos().unwrap())));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown source node: `40`")]
+    fn test_unknown_source() {
+        // Given
+        let map = build_map();
+        let graph = Graph::from_map(&map);
+
+        // When
+        PathFinding::new(&graph, 40);
+    }
+}
+
+mod bfs {
+    use crate::common::build_map;
+    use day_12_hills::{Graph, PathFinding};
+
+    #[test]
+    fn test_predecessors() {
+        // Given
+        let map = build_map();
+        let graph = Graph::from_map(&map);
+        let start_id = graph.get_id(&map.get_start_pos().unwrap());
+        let mut path_finding = PathFinding::new(&graph, start_id);
+
+        // When
+        path_finding.bfs();
+
+        // Then
+        let mut id = graph.get_id(&map.get_end_pos().unwrap());
+        let mut nb_steps = 0;
+        while let Some(predecessor) = path_finding.get_predecessor(id) {
+            assert!(graph.get_node(predecessor).adjacents.contains(&id));
+            id = predecessor;
+            nb_steps += 1;
+        }
+        assert_eq!(id, start_id);
+        assert_eq!(nb_steps, 31);
+    }
+
+    #[test]
+    fn test_all_reachable() {
+        // Given
+        let map = build_map();
+        let graph = Graph::from_map(&map);
+        let mut path_finding =
+            PathFinding::new(&graph, graph.get_id(&map.get_start_pos().unwrap()));
+
+        // When
+        path_finding.bfs();
+
+        // Then
+        assert!(path_finding.get_distances().iter().all(|d| d.is_some()));
+        assert_eq!(
+            path_finding.get_distances().iter().flatten().max(),
+            Some(&31)
+        );
+    }
+}
+
+mod find_path {
+    use crate::common::{build_map, parse_map};
+    use crate::DETOUR;
+    use day_12_hills::{Graph, PathFinding};
+
+    #[test]
+    fn test_from_start_to_end() {
+        // Given
+        let map = build_map();
+        let graph = Graph::from_map(&map);
+        let mut path_finding =
+            PathFinding::new(&graph, graph.get_id(&map.get_start_pos().unwrap()));
+
+        // When
+        let result = path_finding
+            .find_path(graph.get_id(&map.get_end_pos().unwrap()))
+            .unwrap();
+
+        // Then
+        assert_eq!(result.len(), 32);
+        assert_eq!(result.first(), map.get_start_pos().as_ref());
+        assert_eq!(result.last(), map.get_end_pos().as_ref());
+        result.windows(2).for_each(|step| {
+            let from = graph.get_node(graph.get_id(&step[0]));
+            assert!(from.adjacents.contains(&graph.get_id(&step[1])));
+        });
+    }
+
+    #[test]
+    fn test_starts_at_closest_source() {
+        // Given
+        let map = build_map();
+        let graph = Graph::from_map(&map);
+        let mut path_finding = PathFinding::from_sources(&graph, graph.get_ids_at_height(0));
+
+        // When
+        let result = path_finding
+            .find_path(graph.get_id(&map.get_end_pos().unwrap()))
+            .unwrap();
+
+        // Then
+        assert_eq!(result.len(), 30);
+        assert_eq!(map.get_tile_height(&result[0]), 0);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        // Given
+        let map = parse_map(&DETOUR);
+        let graph = Graph::from_map(&map);
+        let mut path_finding =
+            PathFinding::new(&graph, graph.get_id(&map.get_start_pos().unwrap()));
+
+        // When
+        let result = path_finding.find_path(graph.get_id(&map.get_end_pos().unwrap()));
+
+        // Then
+        assert_eq!(result, None);
+    }
+}
+
+mod astar_to {
+    use crate::common::{build_map, parse_map};
+    use crate::DETOUR;
+    use day_12_hills::{Graph, HeightmapFormat, Heuristic, MapFactory, PathFinding};
+    use grid::Pos;
+
+    #[test]
+    fn test_matches_bfs() {
+        // Given
+        let map = build_map();
+        let graph = Graph::from_map(&map);
+        let mut path_finding =
+            PathFinding::new(&graph, graph.get_id(&map.get_start_pos().unwrap()));
+        let end_id = graph.get_id(&map.get_end_pos().unwrap());
+
+        // When
+        let result = [
+            Heuristic::Zero,
+            Heuristic::Manhattan,
+            Heuristic::HeightDifference,
+            Heuristic::Combined,
+        ]
+        .map(|heuristic| path_finding.astar_to(end_id, heuristic));
+
+        // Then
+        assert_eq!(result, [Some(31); 4]);
+        assert_eq!(path_finding.get_path_to(end_id).unwrap().len(), 32);
+    }
+
+    #[test]
+    fn test_expands_fewer_nodes_than_bfs() {
+        // Given
+        let mut map_factory = MapFactory::with_format(HeightmapFormat::Digits);
+        (0..10).for_each(|_| map_factory.parse_line("0000000000"));
+        map_factory.set_end_pos(Pos::new(9, 0));
+        let map = map_factory.create_map();
+        let graph = Graph::from_map(&map);
+        let end_id = graph.get_id(&map.get_end_pos().unwrap());
+        let mut path_finding = PathFinding::new(&graph, 0);
+        let bfs_distance = path_finding.bfs_to(end_id);
+        let nb_expanded_bfs = path_finding.get_nb_expanded();
+
+        // When
+        let result = path_finding.astar_to(end_id, Heuristic::Manhattan);
+
+        // Then
+        assert_eq!(result, bfs_distance);
+        assert_eq!(result, Some(9));
+        assert_eq!(path_finding.get_nb_expanded(), 10);
+        assert_eq!(nb_expanded_bfs, 55);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        // Given
+        let map = parse_map(&DETOUR);
+        let graph = Graph::from_map(&map);
+        let mut path_finding =
+            PathFinding::new(&graph, graph.get_id(&map.get_start_pos().unwrap()));
+
+        // When
+        let result = path_finding.astar_to(
+            graph.get_id(&map.get_end_pos().unwrap()),
+            Heuristic::Combined,
+        );
+
+        // Then
+        assert_eq!(result, None);
+        assert_eq!(path_finding.get_nb_expanded(), 8);
+    }
+}
+
+mod get_nb_expanded {
+    use crate::common::build_map;
+    use day_12_hills::{Graph, PathFinding};
+
+    #[test]
+    fn test_full_bfs() {
+        // Given
+        let map = build_map();
+        let graph = Graph::from_map(&map);
+        let mut path_finding =
+            PathFinding::new(&graph, graph.get_id(&map.get_start_pos().unwrap()));
+
+        // When
+        path_finding.bfs();
+
+        // Then
+        assert_eq!(path_finding.get_nb_expanded(), 40);
+    }
+}
+
+mod dijkstra_to {
+    use crate::common::{build_map, parse_map};
+    use crate::{climb_cost, DETOUR};
+    use day_12_hills::{Graph, PathFinding};
+
+    #[test]
+    fn test_unit_cost_matches_bfs() {
+        // Given
+        let map = build_map();
+        let graph = Graph::from_map(&map);
+        let mut path_finding =
+            PathFinding::new(&graph, graph.get_id(&map.get_start_pos().unwrap()));
+
+        // When
+        let result = path_finding.dijkstra_to(graph.get_id(&map.get_end_pos().unwrap()), |_, _| 1);
+
+        // Then
+        assert_eq!(result, Some(31));
+    }
+
+    #[test]
+    fn test_prefers_cheaper_longer_path() {
+        // Given
+        let graph = Graph::from_map(&parse_map(&DETOUR));
+        let mut path_finding = PathFinding::new(&graph, 0);
+
+        // When
+        let result = path_finding.dijkstra_to(2, climb_cost);
+
+        // Then
+        assert_eq!(result, Some(4));
+        assert_eq!(path_finding.get_predecessor(2), Some(5));
+    }
+}
+
+mod dijkstra {
+    use crate::common::parse_map;
+    use crate::{climb_cost, DETOUR};
+    use day_12_hills::{Graph, PathFinding};
+
+    #[test]
+    fn test_all_distances() {
+        // Given
+        let graph = Graph::from_map(&parse_map(&DETOUR));
+        let mut path_finding = PathFinding::new(&graph, 0);
+
+        // When
+        path_finding.dijkstra(climb_cost);
+
+        // Then
+        assert_eq!(path_finding.get_distance(1), Some(5));
+        assert_eq!(path_finding.get_distance(7), Some(3));
+        assert_eq!(path_finding.get_distance(8), None);
+    }
+}
+
+// Climbing onto the `b` tile is expensive, going around it is cheaper
+const DETOUR: [&str; 3] = ["Sba", "aaa", "aaE"];
+
+fn climb_cost(from: &Node, to: &Node) -> Distance {
+    match to.height > from.height {
+        true => 5,
+        false => 1,
+    }
+}
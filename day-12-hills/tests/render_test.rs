@@ -0,0 +1,85 @@
+mod common;
+
+mod render_path {
+    use crate::common::build_map;
+    use day_12_hills::{render_path, Graph, PathFinding};
+    use grid::Pos;
+
+    #[test]
+    fn test_shortest_path() {
+        // Given
+        let map = build_map();
+        let graph = Graph::from_map(&map);
+        let mut path_finding =
+            PathFinding::new(&graph, graph.get_id(&map.get_start_pos().unwrap()));
+        let path = path_finding
+            .find_path(graph.get_id(&map.get_end_pos().unwrap()))
+            .unwrap();
+
+        // When
+        let result = render_path(&map, &path);
+
+        // Then
+        let expected = ["v..v<<<<", ">v.vv<<^", ".v.v>E^^", ".>v>>>^^", "..>>>>>^"];
+        assert_eq!(result, expected.join("\n"));
+    }
+
+    #[test]
+    fn test_handmade_path() {
+        // Given
+        let map = build_map();
+        let path = vec![
+            Pos::new(1, 1),
+            Pos::new(1, 0),
+            Pos::new(2, 0),
+            Pos::new(2, 1),
+        ];
+
+        // When
+        let result = render_path(&map, &path);
+
+        // Then
+        let expected = [".>v.....", ".^E.....", "........", "........", "........"];
+        assert_eq!(result, expected.join("\n"));
+    }
+
+    #[test]
+    fn test_empty_path() {
+        // Given
+        let map = build_map();
+
+        // When
+        let result = render_path(&map, &[]);
+
+        // Then
+        assert_eq!(result, ["........"; 5].join("\n"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Path positions are not adjacent")]
+    fn test_gap_in_path() {
+        // Given
+        let map = build_map();
+        let path = vec![Pos::new(0, 0), Pos::new(2, 0)];
+
+        // When
+        render_path(&map, &path);
+    }
+}
+
+mod get_direction {
+    use day_12_hills::get_direction;
+    use grid::{Direction, Pos};
+
+    #[test]
+    fn test_orthogonal() {
+        // Given
+        let from = Pos::new(1, 1);
+
+        // When
+        let result = get_direction(&from, &Pos::new(1, 0));
+
+        // Then
+        assert_eq!(result, Direction::Up);
+    }
+}
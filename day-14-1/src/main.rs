@@ -1,12 +1,10 @@
-#![feature(entry_insert)]
-
-use std::collections::hash_map::Entry;
-use std::collections::HashMap;
 use std::env;
 use std::fs::File;
 use std::io::{self, BufRead};
 use std::path::Path;
 
+use grid::{Direction, Point, SparseGrid};
+
 #[derive(PartialEq, Clone)]
 enum Tile {
     Rock,
@@ -37,48 +35,37 @@ impl PosDiff {
         };
         PosDiff { x, y }
     }
-}
-
-#[derive(Hash, Clone, PartialEq, Eq, Debug)]
-struct Pos {
-    x: usize,
-    y: usize,
-}
 
-impl Pos {
-    fn new_sand() -> Self {
-        Pos { x: 500, y: 0 }
-    }
-
-    fn add(&self, other: &PosDiff) -> Self {
-        Pos {
-            x: (self.x as isize + other.x) as usize,
-            y: (self.y as isize + other.y) as usize,
+    fn between(from: &Point, to: &Point) -> Self {
+        PosDiff {
+            x: to.x - from.x,
+            y: to.y - from.y,
         }
     }
 
-    fn sub(&self, other: &Self) -> PosDiff {
-        PosDiff {
-            x: self.x as isize - other.x as isize,
-            y: self.y as isize - other.y as isize,
-        }
+    fn apply(&self, point: &Point) -> Point {
+        Point::new(point.x + self.x, point.y + self.y)
     }
 }
 
+fn new_sand() -> Point {
+    Point::new(500, 0)
+}
+
 struct Scan {
-    depth: usize,
-    content: HashMap<Pos, Tile>,
+    depth: isize,
+    content: SparseGrid<Tile>,
 }
 
 impl Scan {
     fn new() -> Self {
         Scan {
             depth: 1,
-            content: HashMap::new(),
+            content: SparseGrid::new(),
         }
     }
 
-    fn add_rocks_lines(&mut self, rocks_lines: &[Pos]) {
+    fn add_rocks_lines(&mut self, rocks_lines: &[Point]) {
         rocks_lines
             .iter()
             .skip(1)
@@ -89,23 +76,23 @@ impl Scan {
             })
     }
 
-    fn add_rocks_line(&mut self, from_pos: &Pos, to_pos: &Pos) {
-        let mut pos = from_pos.clone();
-        let vector = to_pos.sub(from_pos).to_normal();
+    fn add_rocks_line(&mut self, from_pos: &Point, to_pos: &Point) {
+        let mut pos = *from_pos;
+        let vector = PosDiff::between(from_pos, to_pos).to_normal();
         while &pos != to_pos {
             self.add_rock(&pos);
-            pos = pos.add(&vector);
+            pos = vector.apply(&pos);
         }
         self.add_rock(&pos);
     }
 
-    fn add_rock(&mut self, pos: &Pos) {
-        self.content.insert(pos.clone(), Tile::Rock);
+    fn add_rock(&mut self, pos: &Point) {
+        self.content.insert(*pos, Tile::Rock);
         self.depth = self.depth.max(pos.y + 1);
     }
 
     fn try_generate_sand(&mut self) -> bool {
-        let mut pos = Pos::new_sand();
+        let mut pos = new_sand();
         loop {
             let new_pos = self.try_move_sand(&pos);
             match new_pos {
@@ -118,45 +105,29 @@ impl Scan {
         }
     }
 
-    fn try_move_sand(&self, incoming_sand_pos: &Pos) -> Option<Pos> {
-        let y = incoming_sand_pos.y + 1;
-        let shift: [isize; 3] = [0, -1, 1];
-        for delta_x in shift {
-            if delta_x == -1 && incoming_sand_pos.x == 0 {
-                continue;
-            }
-            let x = (incoming_sand_pos.x as isize + delta_x) as usize;
-            let pos = Pos { x, y };
-            if self.is_air(&pos) {
-                return Some(pos);
-            }
-        }
-        None
+    fn try_move_sand(&self, incoming_sand_pos: &Point) -> Option<Point> {
+        [Direction::Down, Direction::DownLeft, Direction::DownRight]
+            .iter()
+            .map(|direction| incoming_sand_pos.step(*direction))
+            .find(|pos| self.is_air(pos))
     }
 
-    fn try_add_sand(&mut self, pos: &Pos) -> bool {
-        let entry = self.content.entry(pos.clone());
-        match &entry {
-            Entry::Occupied(val) => match val.get() {
-                Tile::Sand => false,
-                Tile::Air => {
-                    entry.insert_entry(Tile::Sand);
-                    true
-                }
-                Tile::Rock => panic!("Cannot add a sand at a rock"),
-            },
-            Entry::Vacant(_) => {
-                entry.insert_entry(Tile::Sand);
+    fn try_add_sand(&mut self, pos: &Point) -> bool {
+        match self.content.get(pos) {
+            Some(Tile::Sand) => false,
+            Some(Tile::Rock) => panic!("Cannot add a sand at a rock"),
+            Some(Tile::Air) | None => {
+                self.content.insert(*pos, Tile::Sand);
                 true
             }
         }
     }
 
-    fn is_air(&self, pos: &Pos) -> bool {
+    fn is_air(&self, pos: &Point) -> bool {
         self.get_tile_content(pos) == Tile::Air
     }
 
-    fn get_tile_content(&self, pos: &Pos) -> Tile {
+    fn get_tile_content(&self, pos: &Point) -> Tile {
         match self.content.get(pos) {
             Some(tile) => tile.clone(),
             None => Tile::Air,
@@ -164,16 +135,16 @@ impl Scan {
     }
 }
 
-fn parse_line(line: &str) -> Vec<Pos> {
+fn parse_line(line: &str) -> Vec<Point> {
     let words = line.split(" -> ");
     words.map(parse_word).collect()
 }
 
-fn parse_word(word: &str) -> Pos {
+fn parse_word(word: &str) -> Point {
     let mut words = word.split(',');
     let x = words.next().unwrap().parse().unwrap();
     let y = words.next().unwrap().parse().unwrap();
-    Pos { x, y }
+    Point::new(x, y)
 }
 
 fn main() {
@@ -0,0 +1,253 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::direction::Direction;
+
+#[derive(Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Pos {
+    pub fn new(x: usize, y: usize) -> Self {
+        Pos { x, y }
+    }
+
+    pub fn step(&self, direction: Direction) -> Option<Self> {
+        let (delta_x, delta_y) = direction.get_offset();
+        let x = self.x.checked_add_signed(delta_x)?;
+        let y = self.y.checked_add_signed(delta_y)?;
+        Some(Pos { x, y })
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Grid {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = match rows.first() {
+            Some(row) => row.len(),
+            None => 0,
+        };
+        let mut cells = Vec::with_capacity(width * height);
+        rows.into_iter().enumerate().for_each(|(y, row)| {
+            if row.len() != width {
+                panic!(
+                    "The row {} should be {} wide, while it is {}",
+                    y,
+                    width,
+                    row.len()
+                );
+            }
+            cells.extend(row);
+        });
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn parse<I, S, F>(lines: I, mut parse_cell: F) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        F: FnMut(Pos, char) -> T,
+    {
+        let rows = lines
+            .into_iter()
+            .enumerate()
+            .map(|(y, line)| {
+                line.as_ref()
+                    .chars()
+                    .enumerate()
+                    .map(|(x, c)| parse_cell(Pos { x, y }, c))
+                    .collect()
+            })
+            .collect();
+        Grid::from_rows(rows)
+    }
+
+    pub fn push_row(&mut self, row: Vec<T>) {
+        if self.height == 0 {
+            self.width = row.len();
+        } else if row.len() != self.width {
+            panic!(
+                "The line should be {}, while it is {}",
+                self.width,
+                row.len()
+            );
+        }
+        self.cells.extend(row);
+        self.height += 1;
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: &Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: &Pos) -> Option<&T> {
+        match self.contains(pos) {
+            true => self.cells.get(pos.x + pos.y * self.width),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: &Pos) -> Option<&mut T> {
+        match self.contains(pos) {
+            true => self.cells.get_mut(pos.x + pos.y * self.width),
+            false => None,
+        }
+    }
+
+    pub fn set(&mut self, pos: &Pos, value: T) {
+        let cell = self
+            .get_mut(pos)
+            .unwrap_or_else(|| panic!("The position {:?} is outside of the grid", pos));
+        *cell = value;
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        if x >= self.width {
+            panic!("The column {} is outside of the grid", x);
+        }
+        (0..self.height).map(move |y| &self.cells[x + y * self.width])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn step(&self, pos: &Pos, direction: Direction) -> Option<Pos> {
+        pos.step(direction)
+            .filter(|next_pos| self.contains(next_pos))
+    }
+
+    pub fn get_neighbours<'a>(
+        &'a self,
+        pos: &Pos,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        let pos = *pos;
+        directions
+            .iter()
+            .filter_map(move |direction| self.step(&pos, *direction))
+    }
+
+    pub fn get_neighbours_4(&self, pos: &Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.get_neighbours(pos, &Direction::ORTHOGONAL)
+    }
+
+    pub fn get_neighbours_8(&self, pos: &Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.get_neighbours(pos, &Direction::ALL)
+    }
+
+    pub fn ray(&self, pos: &Pos, direction: Direction) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            pos: *pos,
+            direction,
+        }
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<&Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: &Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("The position {:?} is outside of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<&Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: &Pos) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("The position {:?} is outside of the grid", pos))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    pos: Pos,
+    direction: Direction,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Pos, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pos = self.grid.step(&self.pos, self.direction)?;
+        Some((self.pos, &self.grid[&self.pos]))
+    }
+}
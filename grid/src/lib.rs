@@ -0,0 +1,7 @@
+mod dense;
+mod direction;
+mod sparse;
+
+pub use dense::{Grid, Pos, Ray};
+pub use direction::Direction;
+pub use sparse::{Point, SparseGrid};
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::direction::Direction;
+
+const EMPTY_CELL: char = '.';
+
+#[derive(Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    pub fn step(&self, direction: Direction) -> Self {
+        let (delta_x, delta_y) = direction.get_offset();
+        Point {
+            x: self.x + delta_x,
+            y: self.y + delta_y,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn parse<I, S, F>(lines: I, mut parse_cell: F) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        F: FnMut(Point, char) -> Option<T>,
+    {
+        let mut grid = SparseGrid::new();
+        lines.into_iter().enumerate().for_each(|(y, line)| {
+            line.as_ref().chars().enumerate().for_each(|(x, c)| {
+                let point = Point::new(x as isize, y as isize);
+                if let Some(value) = parse_cell(point, c) {
+                    grid.insert(point, value);
+                }
+            })
+        });
+        grid
+    }
+
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        self.cells.get(point)
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        self.cells.get_mut(point)
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: &Point) -> Option<T> {
+        self.cells.remove(point)
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.cells.contains_key(point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Point, &T)> {
+        self.cells.iter()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    pub fn get_bounds(&self) -> Option<(Point, Point)> {
+        self.cells.keys().fold(None, |bounds, point| match bounds {
+            None => Some((*point, *point)),
+            Some((min, max)) => Some((
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            )),
+        })
+    }
+
+    pub fn get_neighbours<'a>(
+        &'a self,
+        point: &Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Point, Option<&'a T>)> + 'a {
+        let point = *point;
+        directions.iter().map(move |direction| {
+            let neighbour = point.step(*direction);
+            (neighbour, self.get(&neighbour))
+        })
+    }
+
+    pub fn get_neighbours_4(&self, point: &Point) -> impl Iterator<Item = (Point, Option<&T>)> {
+        self.get_neighbours(point, &Direction::ORTHOGONAL)
+    }
+
+    pub fn get_neighbours_8(&self, point: &Point) -> impl Iterator<Item = (Point, Option<&T>)> {
+        self.get_neighbours(point, &Direction::ALL)
+    }
+
+    // Follows the direction until a cell is set, or until the ray cannot come back in bounds.
+    pub fn cast_ray(&self, point: &Point, direction: Direction) -> Option<(Point, &T)> {
+        let (min, max) = self.get_bounds()?;
+        let (delta_x, delta_y) = direction.get_offset();
+        let mut point = point.step(direction);
+        loop {
+            if let Some(value) = self.get(&point) {
+                return Some((point, value));
+            }
+            let is_leaving = (point.x < min.x && delta_x <= 0)
+                || (point.x > max.x && delta_x >= 0)
+                || (point.y < min.y && delta_y <= 0)
+                || (point.y > max.y && delta_y >= 0);
+            if is_leaving {
+                return None;
+            }
+            point = point.step(direction);
+        }
+    }
+
+    pub fn render<F: Fn(Option<&T>) -> char>(&self, render_cell: F) -> String {
+        let (min, max) = match self.get_bounds() {
+            Some(bounds) => bounds,
+            None => return String::new(),
+        };
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| render_cell(self.get(&Point::new(x, y))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min, max) = match self.get_bounds() {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+        for y in min.y..=max.y {
+            if y > min.y {
+                writeln!(f)?;
+            }
+            for x in min.x..=max.x {
+                match self.get(&Point::new(x, y)) {
+                    Some(value) => write!(f, "{}", value)?,
+                    None => write!(f, "{}", EMPTY_CELL)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use grid::Grid;
+
+mod parse {
+    use crate::build_grid;
+    use grid::{Grid, Pos};
+
+    #[test]
+    fn test_dimensions() {
+        // Given
+        let grid = build_grid();
+
+        // When
+        let width = grid.get_width();
+        let height = grid.get_height();
+
+        // Then
+        assert_eq!((width, height), (4, 3));
+        assert_eq!(grid[&Pos::new(3, 1)], 7);
+    }
+
+    #[test]
+    fn test_push_row() {
+        // Given
+        let mut grid = Grid::default();
+
+        // When
+        grid.push_row(vec![1, 2]);
+        grid.push_row(vec![3, 4]);
+
+        // Then
+        assert_eq!((grid.get_width(), grid.get_height()), (2, 2));
+        assert_eq!(grid[&Pos::new(0, 1)], 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ragged_rows() {
+        Grid::parse(["123", "12"], |_, c| c);
+    }
+}
+
+mod access {
+    use crate::build_grid;
+    use grid::Pos;
+
+    #[test]
+    fn test_out_of_bounds() {
+        // Given
+        let grid = build_grid();
+
+        // When
+        let result = grid.get(&Pos::new(4, 0));
+
+        // Then
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        // Given
+        let grid = build_grid();
+
+        // When
+        let row = grid.row(1);
+        let column: Vec<u32> = grid.column(2).copied().collect();
+
+        // Then
+        assert_eq!(row, &[4, 5, 6, 7]);
+        assert_eq!(column, vec![2, 6, 0]);
+        assert_eq!(grid.rows().count(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "The column 4 is outside of the grid")]
+    fn test_column_out_of_bounds() {
+        // Given
+        let grid = build_grid();
+
+        // When
+        let _column = grid.column(4);
+    }
+
+    #[test]
+    fn test_set_and_map() {
+        // Given
+        let mut grid = build_grid();
+
+        // When
+        grid.set(&Pos::new(0, 0), 9);
+        let result = grid.map(|value| value * 2);
+
+        // Then
+        assert_eq!(result[&Pos::new(0, 0)], 18);
+        assert_eq!(result[&Pos::new(1, 2)], 18);
+    }
+}
+
+mod neighbours {
+    use crate::build_grid;
+    use grid::Pos;
+
+    #[test]
+    fn test_neighbours_4_in_corner() {
+        // Given
+        let grid = build_grid();
+
+        // When
+        let result: Vec<Pos> = grid.get_neighbours_4(&Pos::new(0, 0)).collect();
+
+        // Then
+        assert_eq!(result, vec![Pos::new(0, 1), Pos::new(1, 0)]);
+    }
+
+    #[test]
+    fn test_neighbours_8() {
+        // Given
+        let grid = build_grid();
+
+        // When
+        let in_middle = grid.get_neighbours_8(&Pos::new(1, 1)).count();
+        let in_corner = grid.get_neighbours_8(&Pos::new(3, 2)).count();
+
+        // Then
+        assert_eq!(in_middle, 8);
+        assert_eq!(in_corner, 3);
+    }
+}
+
+mod ray {
+    use crate::build_grid;
+    use grid::{Direction, Pos};
+
+    #[test]
+    fn test_rays() {
+        // Given
+        let grid = build_grid();
+        let pos = Pos::new(1, 1);
+
+        // When
+        let left: Vec<u32> = grid.ray(&pos, Direction::Left).map(|(_, v)| *v).collect();
+        let right: Vec<u32> = grid.ray(&pos, Direction::Right).map(|(_, v)| *v).collect();
+        let down_right: Vec<Pos> = grid
+            .ray(&pos, Direction::DownRight)
+            .map(|(p, _)| p)
+            .collect();
+
+        // Then
+        assert_eq!(left, vec![4]);
+        assert_eq!(right, vec![6, 7]);
+        assert_eq!(down_right, vec![Pos::new(2, 2)]);
+    }
+}
+
+mod display {
+    use crate::build_grid;
+
+    #[test]
+    fn test_display() {
+        // Given
+        let grid = build_grid();
+
+        // When
+        let result = format!("{}", grid);
+
+        // Then
+        assert_eq!(result, "0123\n4567\n8901");
+    }
+}
+
+fn build_grid() -> Grid<u32> {
+    Grid::parse(["0123", "4567", "8901"], |_, c| c.to_digit(10).unwrap())
+}
@@ -0,0 +1,129 @@
+use grid::SparseGrid;
+
+mod access {
+    use crate::build_grid;
+    use grid::Point;
+
+    #[test]
+    fn test_parse_and_bounds() {
+        // Given
+        let grid = build_grid();
+
+        // When
+        let result = grid.get_bounds();
+
+        // Then
+        assert_eq!(grid.len(), 4);
+        assert_eq!(result, Some((Point::new(0, 0), Point::new(3, 2))));
+    }
+
+    #[test]
+    fn test_negative_points() {
+        // Given
+        let mut grid = build_grid();
+
+        // When
+        grid.insert(Point::new(-2, -1), '@');
+
+        // Then
+        assert_eq!(grid.get(&Point::new(-2, -1)), Some(&'@'));
+        assert_eq!(
+            grid.get_bounds(),
+            Some((Point::new(-2, -1), Point::new(3, 2)))
+        );
+    }
+}
+
+mod neighbours {
+    use crate::build_grid;
+    use grid::Point;
+
+    #[test]
+    fn test_neighbours() {
+        // Given
+        let grid = build_grid();
+
+        // When
+        let set_4 = grid
+            .get_neighbours_4(&Point::new(2, 1))
+            .filter(|(_, value)| value.is_some())
+            .count();
+        let set_8 = grid
+            .get_neighbours_8(&Point::new(2, 1))
+            .filter(|(_, value)| value.is_some())
+            .count();
+
+        // Then
+        assert_eq!(set_4, 1);
+        assert_eq!(set_8, 3);
+    }
+}
+
+mod cast_ray {
+    use crate::build_grid;
+    use grid::{Direction, Point};
+
+    #[test]
+    fn test_hit() {
+        // Given
+        let grid = build_grid();
+
+        // When
+        let result = grid.cast_ray(&Point::new(0, 2), Direction::Right);
+
+        // Then
+        assert_eq!(result, Some((Point::new(3, 2), &'#')));
+    }
+
+    #[test]
+    fn test_from_outside() {
+        // Given
+        let grid = build_grid();
+
+        // When
+        let result = grid.cast_ray(&Point::new(1, -5), Direction::Down);
+
+        // Then
+        assert_eq!(result, Some((Point::new(1, 1), &'#')));
+    }
+
+    #[test]
+    fn test_miss() {
+        // Given
+        let grid = build_grid();
+
+        // When
+        let result = grid.cast_ray(&Point::new(2, 1), Direction::Up);
+
+        // Then
+        assert_eq!(result, None);
+    }
+}
+
+mod display {
+    use crate::build_grid;
+
+    #[test]
+    fn test_display() {
+        // Given
+        let grid = build_grid();
+
+        // When
+        let result = format!("{}", grid);
+        let rendered = grid.render(|value| match value {
+            Some(_) => 'X',
+            None => ' ',
+        });
+
+        // Then
+        assert_eq!(result, "#..#\n.#..\n...#");
+        assert_eq!(rendered, "X  X\n X  \n   X");
+    }
+}
+
+fn build_grid() -> SparseGrid<char> {
+    SparseGrid::parse(["#..#", ".#..", "...#"], |_, c| match c {
+        '#' => Some(c),
+        _ => None,
+    })
+}